@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount, Transfer};
+
+declare_id!("88NvtQQLmxUfkEbcGsLK44kbivdBCvdHkfy6KL3V9c2T");
+
+// Pyth v2 price account layout, as read by trading_vault's oracle module
+const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+pub const PRICE_ACCOUNT_LEN: usize = 240;
+
+// Stand-in DEX and price feed for exercising the vault's swap path on a local validator
+#[program]
+pub mod mock_swap {
+    use super::*;
+
+    // Takes `amount_in` from the trader and pays a fixed `amount_out` out of the pool
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, amount_out: u64) -> Result<()> {
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.trader_source.to_account_info(),
+                    to: ctx.accounts.pool_source.to_account_info(),
+                    authority: ctx.accounts.trader.to_account_info(),
+                },
+            ),
+            amount_in,
+        )?;
+
+        let pool_seeds: &[&[&[u8]]] = &[&[b"pool_authority", &[ctx.bumps.pool_authority]]];
+        anchor_spl::token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.pool_destination.to_account_info(),
+                    to: ctx.accounts.trader_destination.to_account_info(),
+                    authority: ctx.accounts.pool_authority.to_account_info(),
+                },
+            )
+            .with_signer(pool_seeds),
+            amount_out,
+        )
+    }
+
+    // Publishes a trading price at the current time into a price account owned by this program
+    pub fn set_price(ctx: Context<SetPrice>, price: i64, conf: u64, expo: i32) -> Result<()> {
+        let mut data = ctx.accounts.price_account.try_borrow_mut_data()?;
+        require!(
+            data.len() >= PRICE_ACCOUNT_LEN,
+            ErrorCode::AccountDidNotDeserialize
+        );
+
+        data[0..4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[8..12].copy_from_slice(&PYTH_ACCOUNT_TYPE_PRICE.to_le_bytes());
+        data[20..24].copy_from_slice(&expo.to_le_bytes());
+        data[96..104].copy_from_slice(&Clock::get()?.unix_timestamp.to_le_bytes());
+        data[208..216].copy_from_slice(&price.to_le_bytes());
+        data[216..224].copy_from_slice(&conf.to_le_bytes());
+        data[224..228].copy_from_slice(&PYTH_STATUS_TRADING.to_le_bytes());
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub trader: Signer<'info>,
+    #[account(mut)]
+    pub trader_source: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub trader_destination: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub pool_source: Account<'info, TokenAccount>,
+    #[account(mut, token::authority = pool_authority)]
+    pub pool_destination: Account<'info, TokenAccount>,
+    /// CHECK: signs for the pool's token accounts
+    #[account(seeds = [b"pool_authority"], bump)]
+    pub pool_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SetPrice<'info> {
+    pub payer: Signer<'info>,
+    /// CHECK: raw price account, allocated by the caller with this program as owner
+    #[account(mut, owner = crate::ID)]
+    pub price_account: UncheckedAccount<'info>,
+}
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::VaultError, BPS_DENOMINATOR, SECONDS_PER_YEAR, TOKEN_DECIMALS};
+
+// Fixed-point scale used for NAV per bond (1e12)
+pub const NAV_PRECISION: u128 = 1_000_000_000_000;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+// Computes `value * numerator / denominator` in u128 with an explicit rounding direction
+pub fn mul_div(
+    value: u128,
+    numerator: u128,
+    denominator: u128,
+    rounding: Rounding,
+) -> Result<u128> {
+    require!(denominator != 0, VaultError::MathOverflow);
+
+    let product = value
+        .checked_mul(numerator)
+        .ok_or(VaultError::MathOverflow)?;
+    let quotient = product / denominator;
+
+    if rounding == Rounding::Up && product % denominator != 0 {
+        return quotient
+            .checked_add(1)
+            .ok_or(VaultError::MathOverflow.into());
+    }
+    Ok(quotient)
+}
+
+fn to_u64(value: u128) -> Result<u64> {
+    u64::try_from(value).map_err(|_| VaultError::MathOverflow.into())
+}
+
+// NAV per bond base unit, scaled by NAV_PRECISION. An empty vault prices bonds 1:1 with the quote token.
+pub fn nav_per_share(total_assets: u64, total_shares: u64) -> Result<u128> {
+    if total_shares == 0 {
+        return Ok(NAV_PRECISION);
+    }
+    mul_div(
+        total_assets as u128,
+        NAV_PRECISION,
+        total_shares as u128,
+        Rounding::Down,
+    )
+}
+
+// Bonds corresponding to `assets` quote tokens.
+// Use `Rounding::Down` when minting and `Rounding::Up` when burning so the vault never loses value.
+pub fn shares_for_assets(
+    assets: u64,
+    total_assets: u64,
+    total_shares: u64,
+    rounding: Rounding,
+) -> Result<u64> {
+    if total_shares == 0 {
+        return Ok(assets);
+    }
+    require!(total_assets != 0, VaultError::ZeroNav);
+
+    to_u64(mul_div(
+        assets as u128,
+        total_shares as u128,
+        total_assets as u128,
+        rounding,
+    )?)
+}
+
+// Quote tokens corresponding to `shares` bonds.
+// Use `Rounding::Down` when paying out and `Rounding::Up` when charging so the vault never loses value.
+pub fn assets_for_shares(
+    shares: u64,
+    total_assets: u64,
+    total_shares: u64,
+    rounding: Rounding,
+) -> Result<u64> {
+    if total_shares == 0 {
+        return Ok(shares);
+    }
+
+    to_u64(mul_div(
+        shares as u128,
+        total_assets as u128,
+        total_shares as u128,
+        rounding,
+    )?)
+}
+
+// Price of one whole bond (10^TOKEN_DECIMALS base units) in quote base units
+pub fn bond_price(total_assets: u64, total_shares: u64) -> Result<u64> {
+    let nav = nav_per_share(total_assets, total_shares)?;
+    to_u64(mul_div(
+        nav,
+        10u128.pow(TOKEN_DECIMALS as u32),
+        NAV_PRECISION,
+        Rounding::Down,
+    )?)
+}
+
+// Fee of `fee_bps` on `amount`, rounded up in favour of the vault
+pub fn fee_amount(amount: u64, fee_bps: u16) -> Result<u64> {
+    to_u64(mul_div(
+        amount as u128,
+        fee_bps as u128,
+        BPS_DENOMINATOR as u128,
+        Rounding::Up,
+    )?)
+}
+
+// Splits a fee into the leader's and the protocol's share; the protocol share is rounded down
+pub fn split_fee(fee: u64, protocol_fee_bps: u16) -> Result<(u64, u64)> {
+    let protocol_share = to_u64(mul_div(
+        fee as u128,
+        protocol_fee_bps as u128,
+        BPS_DENOMINATOR as u128,
+        Rounding::Down,
+    )?)?;
+    Ok((fee - protocol_share, protocol_share))
+}
+
+// Performance fee owed on the gain of NAV per bond above the high-water mark, rounded down
+pub fn performance_fee(
+    nav: u128,
+    high_water_mark: u128,
+    total_shares: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    if nav <= high_water_mark || fee_bps == 0 {
+        return Ok(0);
+    }
+    let profit = mul_div(
+        nav - high_water_mark,
+        total_shares as u128,
+        NAV_PRECISION,
+        Rounding::Down,
+    )?;
+    to_u64(mul_div(
+        profit,
+        fee_bps as u128,
+        BPS_DENOMINATOR as u128,
+        Rounding::Down,
+    )?)
+}
+
+// Annualized management fee on `total_assets` for `elapsed` seconds, rounded down
+pub fn management_fee(total_assets: u64, fee_bps: u16, elapsed: u64) -> Result<u64> {
+    if fee_bps == 0 || elapsed == 0 {
+        return Ok(0);
+    }
+    let rate = (fee_bps as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(VaultError::MathOverflow)?;
+    to_u64(mul_div(
+        total_assets as u128,
+        rate,
+        BPS_DENOMINATOR as u128 * SECONDS_PER_YEAR as u128,
+        Rounding::Down,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_div_rounds_in_the_requested_direction() {
+        assert_eq!(mul_div(10, 1, 3, Rounding::Down).unwrap(), 3);
+        assert_eq!(mul_div(10, 1, 3, Rounding::Up).unwrap(), 4);
+        assert_eq!(mul_div(9, 1, 3, Rounding::Up).unwrap(), 3);
+    }
+
+    #[test]
+    fn mul_div_rejects_zero_denominator_and_overflow() {
+        assert!(mul_div(1, 1, 0, Rounding::Down).is_err());
+        assert!(mul_div(u128::MAX, 2, 1, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn empty_vault_prices_bonds_one_to_one() {
+        assert_eq!(nav_per_share(0, 0).unwrap(), NAV_PRECISION);
+        assert_eq!(shares_for_assets(500, 0, 0, Rounding::Down).unwrap(), 500);
+        assert_eq!(assets_for_shares(500, 0, 0, Rounding::Down).unwrap(), 500);
+        assert_eq!(bond_price(0, 0).unwrap(), 10u64.pow(TOKEN_DECIMALS as u32));
+    }
+
+    #[test]
+    fn outstanding_bonds_without_assets_cannot_be_minted_against() {
+        assert!(shares_for_assets(100, 0, 1_000, Rounding::Down).is_err());
+        assert_eq!(nav_per_share(0, 1_000).unwrap(), 0);
+        assert_eq!(assets_for_shares(100, 0, 1_000, Rounding::Down).unwrap(), 0);
+    }
+
+    #[test]
+    fn conversions_round_in_favour_of_the_vault() {
+        // 3 bonds backed by 10 quote units
+        assert_eq!(shares_for_assets(5, 10, 3, Rounding::Down).unwrap(), 1);
+        assert_eq!(shares_for_assets(5, 10, 3, Rounding::Up).unwrap(), 2);
+        assert_eq!(assets_for_shares(1, 10, 3, Rounding::Down).unwrap(), 3);
+        assert_eq!(assets_for_shares(1, 10, 3, Rounding::Up).unwrap(), 4);
+    }
+
+    #[test]
+    fn bond_price_tracks_nav() {
+        // 2 quote units per bond base unit
+        assert_eq!(
+            bond_price(2_000_000, 1_000_000).unwrap(),
+            2 * 10u64.pow(TOKEN_DECIMALS as u32)
+        );
+    }
+
+    #[test]
+    fn fee_amount_rounds_up() {
+        assert_eq!(fee_amount(10_000, 30).unwrap(), 30);
+        assert_eq!(fee_amount(1, 30).unwrap(), 1);
+        assert_eq!(fee_amount(1_000, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn split_fee_rounds_the_protocol_share_down() {
+        assert_eq!(split_fee(100, 2_000).unwrap(), (80, 20));
+        assert_eq!(split_fee(9, 2_000).unwrap(), (8, 1));
+        assert_eq!(split_fee(100, 0).unwrap(), (100, 0));
+        assert_eq!(split_fee(100, 10_000).unwrap(), (0, 100));
+    }
+
+    #[test]
+    fn performance_fee_only_charges_gains_above_the_mark() {
+        let shares = 1_000_000;
+        assert_eq!(
+            performance_fee(NAV_PRECISION, NAV_PRECISION, shares, 2_000).unwrap(),
+            0
+        );
+        assert_eq!(
+            performance_fee(NAV_PRECISION / 2, NAV_PRECISION, shares, 2_000).unwrap(),
+            0
+        );
+        // NAV up 10% on 1_000_000 bonds is 100_000 of profit, 20% of which is owed
+        assert_eq!(
+            performance_fee(NAV_PRECISION * 11 / 10, NAV_PRECISION, shares, 2_000).unwrap(),
+            20_000
+        );
+        assert_eq!(
+            performance_fee(NAV_PRECISION * 2, NAV_PRECISION, shares, 0).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn management_fee_accrues_pro_rata_over_the_year() {
+        assert_eq!(
+            management_fee(1_000_000, 200, SECONDS_PER_YEAR).unwrap(),
+            20_000
+        );
+        assert_eq!(
+            management_fee(1_000_000, 200, SECONDS_PER_YEAR / 2).unwrap(),
+            10_000
+        );
+        assert_eq!(management_fee(1_000_000, 200, 0).unwrap(), 0);
+        assert_eq!(management_fee(1_000_000, 0, SECONDS_PER_YEAR).unwrap(), 0);
+    }
+}
@@ -2,3 +2,53 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const TOKEN_DECIMALS: u8 = 6;
+
+#[constant]
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+#[constant]
+pub const MAX_PERFORMANCE_FEE_BPS: u16 = 5_000;
+
+#[constant]
+pub const MAX_MANAGEMENT_FEE_BPS: u16 = 500;
+
+#[constant]
+pub const SECONDS_PER_YEAR: u64 = 365 * 86_400;
+
+#[constant]
+pub const MAX_ENTRY_EXIT_FEE_BPS: u16 = 500;
+
+#[constant]
+pub const MAX_QUOTE_MINTS: usize = 8;
+
+#[constant]
+pub const MAX_VAULT_ASSETS: usize = 8;
+
+#[constant]
+pub const MAX_SWAP_PROGRAMS: usize = 8;
+
+#[constant]
+pub const MAX_VAULT_POSITIONS: u8 = 16;
+
+// pause flags combined in `Vault::pause_flags`
+#[constant]
+pub const PAUSE_DEPOSITS: u8 = 1;
+
+#[constant]
+pub const PAUSE_WITHDRAWALS: u8 = 2;
+
+#[constant]
+pub const PAUSE_TRADING: u8 = 4;
+
+#[constant]
+pub const PAUSE_NAV_REPORTING: u8 = 8;
+
+#[constant]
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSITS | PAUSE_WITHDRAWALS | PAUSE_TRADING | PAUSE_NAV_REPORTING;
+
+// incident mode: depositors can still exit at the last NAV
+#[constant]
+pub const WITHDRAW_ONLY: u8 = PAUSE_DEPOSITS | PAUSE_TRADING | PAUSE_NAV_REPORTING;
+
+#[constant]
+pub const MAX_STRATEGY_ID_LEN: usize = 32;
@@ -8,4 +8,148 @@ pub enum VaultError {
     InsufficientFunds,
     #[msg("Lock period not over.")]
     LockPeriodNotOver,
+    #[msg("Lock period must not be negative.")]
+    InvalidLockPeriod,
+    #[msg("Lock period can only be shortened.")]
+    LockPeriodIncrease,
+    #[msg("Arithmetic overflow.")]
+    MathOverflow,
+    #[msg("Vault has outstanding bonds but no assets.")]
+    ZeroNav,
+    #[msg("Amount is too small to mint any bonds.")]
+    ZeroBonds,
+    #[msg("Fee rate exceeds the allowed maximum.")]
+    InvalidFeeRate,
+    #[msg("Fee recipient does not match the vault.")]
+    InvalidFeeRecipient,
+    #[msg("Too many allowed quote mints.")]
+    TooManyQuoteMints,
+    #[msg("Quote mint is not allowed by the protocol.")]
+    QuoteMintNotAllowed,
+    #[msg("Protocol is paused.")]
+    ProtocolPaused,
+    #[msg("NAV report submitted before the minimum interval elapsed.")]
+    NavReportTooFrequent,
+    #[msg("NAV change exceeds the allowed bound per report.")]
+    NavChangeTooLarge,
+    #[msg("NAV reporting interval must not be negative.")]
+    InvalidNavReportInterval,
+    #[msg("Account is not a valid oracle price account.")]
+    InvalidOracleAccount,
+    #[msg("Oracle price is not available.")]
+    InvalidOraclePrice,
+    #[msg("Oracle price is stale.")]
+    StaleOraclePrice,
+    #[msg("Oracle price confidence interval is too wide.")]
+    OracleConfidenceTooWide,
+    #[msg("Oracle account does not match the registered asset.")]
+    OracleMismatch,
+    #[msg("Vault holds the maximum number of assets.")]
+    TooManyAssets,
+    #[msg("Holdings do not match the registered assets.")]
+    AssetCountMismatch,
+    #[msg("Custody account does not match the registered asset.")]
+    CustodyMismatch,
+    #[msg("The quote mint cannot be registered as a held asset.")]
+    QuoteMintAsAsset,
+    #[msg("Vault token account does not match the vault.")]
+    InvalidVaultTokenAccount,
+    #[msg("Token account does not hold the vault's quote mint.")]
+    InvalidQuoteMint,
+    #[msg("Token account does not hold the vault's bond mint.")]
+    InvalidBondMint,
+    #[msg("Token account is not owned by the expected wallet.")]
+    InvalidTokenOwner,
+    #[msg("Deposit would exceed the vault's TVL cap.")]
+    VaultCapExceeded,
+    #[msg("Deposit would exceed the per-depositor cap.")]
+    UserDepositCapExceeded,
+    #[msg("Depositor is not on the vault's allowlist.")]
+    NotAllowlisted,
+    #[msg("Vault prices deposits and redemptions by epoch.")]
+    EpochPricingEnabled,
+    #[msg("Vault does not use epoch pricing.")]
+    EpochPricingDisabled,
+    #[msg("Epoch has not ended yet.")]
+    EpochNotOver,
+    #[msg("Epoch has not been settled yet.")]
+    EpochNotSettled,
+    #[msg("Epoch length must not be negative.")]
+    InvalidEpochLength,
+    #[msg("Too many allowed swap programs.")]
+    TooManySwapPrograms,
+    #[msg("Swap program is not allowed by the protocol.")]
+    SwapProgramNotAllowed,
+    #[msg("Trading is paused for this vault.")]
+    TradingPaused,
+    #[msg("Swap returned less than the minimum output.")]
+    SlippageExceeded,
+    #[msg("Swap spent more than the input amount.")]
+    SwapInputExceeded,
+    #[msg("Swap token accounts must be held by the vault.")]
+    InvalidSwapAccount,
+    #[msg("Too many open positions.")]
+    TooManyPositions,
+    #[msg("Position accounts do not match the vault's open positions.")]
+    PositionCountMismatch,
+    #[msg("Position does not belong to the vault.")]
+    PositionMismatch,
+    #[msg("Position size must be greater than zero.")]
+    InvalidPositionSize,
+    #[msg("Deposits are paused for this vault.")]
+    DepositsPaused,
+    #[msg("Withdrawals are paused for this vault.")]
+    WithdrawalsPaused,
+    #[msg("NAV reporting is paused for this vault.")]
+    NavReportingPaused,
+    #[msg("Unknown pause flag.")]
+    InvalidPauseFlags,
+    #[msg("Signer is not allowed to perform this action.")]
+    Unauthorized,
+    #[msg("Drawdown limit must not exceed 100%.")]
+    InvalidDrawdownLimit,
+    #[msg("Drawdown cooldown must not be negative.")]
+    InvalidDrawdownCooldown,
+    #[msg("Trading cannot resume before the drawdown cooldown ends.")]
+    DrawdownCooldownActive,
+    #[msg("Signer is not the pending leader.")]
+    InvalidPendingLeader,
+    #[msg("Parameter change delay must not be negative.")]
+    InvalidParamChangeDelay,
+    #[msg("Parameter change delay has not passed.")]
+    ParamChangeDelayNotOver,
+    #[msg("Strategy id is too long.")]
+    StrategyIdTooLong,
+    #[msg("Role must be transferred through its dedicated instructions.")]
+    RoleNotAssignable,
+    #[msg("Governance quorum, threshold or voting period is invalid.")]
+    InvalidGovernanceParams,
+    #[msg("Signer holds no bonds of the vault.")]
+    NoVotingPower,
+    #[msg("Changes approved by bond holders cannot be cancelled.")]
+    GovernanceChangeNotCancellable,
+    #[msg("Voting on the proposal has closed.")]
+    VotingClosed,
+    #[msg("Voting on the proposal is still open.")]
+    VotingNotOver,
+    #[msg("Proposal has already been executed.")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal did not reach quorum and threshold.")]
+    ProposalNotPassed,
+    #[msg("Vault has been terminated.")]
+    VaultTerminated,
+    #[msg("Vault already holds deposits.")]
+    VaultAlreadyFunded,
+    #[msg("Pending parameter change account is required.")]
+    MissingParamChangeAccount,
+    #[msg("Bond name, symbol or uri is too long.")]
+    InvalidBondMetadata,
+    #[msg("Swap changed the owner or delegate of a vault token account.")]
+    SwapAccountTampered,
+    #[msg("Epoch does not belong to the vault or did not price these deposits.")]
+    InvalidEpoch,
+    #[msg("Withdrawals were paused during the parameter change delay.")]
+    ExitWindowInterrupted,
+    #[msg("Bonds were added to the vote lock after the proposal was created.")]
+    LockedAfterProposal,
 }
@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct DrawdownCircuitBreakerTripped {
+    pub vault: Pubkey,
+    // NAV per bond scaled by NAV_PRECISION
+    pub nav: u128,
+    pub peak_nav: u128,
+    pub drawdown_bps: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Vault};
+
+#[derive(Accounts)]
+pub struct AcceptLeader<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        constraint = vault.pending_leader == new_leader.key() @ VaultError::InvalidPendingLeader,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub new_leader: Signer<'info>,
+}
+
+// Completes a leadership transfer proposed by the current leader
+pub fn accept_leader(ctx: Context<AcceptLeader>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    msg!(
+        "Leadership transferred from {} to {}",
+        vault.leader,
+        vault.pending_leader
+    );
+
+    let new_leader = vault.pending_leader;
+    vault.hand_over_leadership(new_leader);
+    vault.pending_leader = Pubkey::default();
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::Vault;
+
+#[derive(Accounts)]
+pub struct AccrueFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+// Accrues management and performance fees; callable by anyone
+pub fn accrue_fees(ctx: Context<AccrueFees>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.accrue_fees(Clock::get()?.unix_timestamp)?;
+    vault.refresh_bond_price()?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, PendingParamChange, Vault};
+
+#[derive(Accounts)]
+pub struct CancelParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub leader: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"param_change", vault.key().as_ref()],
+        bump = pending_param_change.bump,
+        constraint = !pending_param_change.by_governance @ VaultError::GovernanceChangeNotCancellable,
+        close = leader,
+    )]
+    pub pending_param_change: Account<'info, PendingParamChange>,
+}
+
+// Drops the queued change of terms and closes the exit window
+pub fn cancel_param_change(ctx: Context<CancelParamChange>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.param_change_executable_at = 0;
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{error::*, User, Vault, WithdrawRequest};
+
+#[derive(Accounts)]
+pub struct CancelWithdraw<'info> {
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = user.bump,
+    )]
+    pub user: Account<'info, User>,
+    #[account(
+        mut,
+        seeds = [b"withdraw_request", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = withdraw_request.bump,
+        has_one = depositor,
+        close = depositor,
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = depositor_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"bond_escrow", vault.key().as_ref()],
+        bump,
+    )]
+    pub bond_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Withdraws a pending request and hands the escrowed bonds back to the depositor
+pub fn cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &ctx.accounts.vault;
+    let withdraw_request = &ctx.accounts.withdraw_request;
+
+    vault.transfer_tokens(
+        vault_key,
+        ctx.accounts.bond_escrow.to_account_info(),
+        ctx.accounts.depositor_token_account.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        withdraw_request.bond_amount,
+    )?;
+
+    // restore the position and cost basis released when the request was made
+    let user = &mut ctx.accounts.user;
+    user.bond_amount += withdraw_request.bond_amount;
+    user.deposit_value += withdraw_request.deposit_value;
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Proposal, Vault, VoteLock, VoteRecord};
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = vault,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    // one vote per holder and proposal
+    #[account(
+        init,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+        payer = voter,
+        space = VoteRecord::LEN
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(
+        seeds = [b"vote_lock", vault.key().as_ref(), voter.key().as_ref()],
+        bump = vote_lock.bump,
+    )]
+    pub vote_lock: Account<'info, VoteLock>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CastVoteParams {
+    pub approve: bool,
+}
+
+// Votes on a proposal with the bonds the holder had locked before it was created. A lock topped up
+// since then no longer reflects the snapshot, so it can't vote on the proposal at all.
+pub fn cast_vote(ctx: Context<CastVote>, params: CastVoteParams) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let vote_lock = &ctx.accounts.vote_lock;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        current_time < proposal.voting_ends_at,
+        VaultError::VotingClosed
+    );
+    require!(
+        vote_lock.last_locked_at < proposal.created_at,
+        VaultError::LockedAfterProposal
+    );
+
+    let weight = vote_lock.amount;
+    require!(weight > 0, VaultError::NoVotingPower);
+
+    if params.approve {
+        proposal.yes_votes += weight;
+    } else {
+        proposal.no_votes += weight;
+    }
+
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.weight = weight;
+    vote_record.approve = params.approve;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    accounting::{mul_div, Rounding},
+    error::*,
+    Epoch, EpochClaim, User, Vault, PAUSE_WITHDRAWALS,
+};
+
+#[derive(Accounts)]
+#[instruction(params: ClaimEpochParams)]
+pub struct ClaimEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = user.bump,
+    )]
+    pub user: Account<'info, User>,
+    #[account(
+        seeds = [b"epoch", vault.key().as_ref(), params.epoch_id.to_le_bytes().as_ref()],
+        bump = epoch.bump,
+    )]
+    pub epoch: Account<'info, Epoch>,
+    // the later epoch that priced this epoch's deposits, required when they were carried
+    #[account(
+        constraint = deposit_epoch.vault == vault.key() @ VaultError::InvalidEpoch,
+    )]
+    pub deposit_epoch: Option<Account<'info, Epoch>>,
+    #[account(
+        mut,
+        seeds = [
+            b"epoch_claim",
+            vault.key().as_ref(),
+            params.epoch_id.to_le_bytes().as_ref(),
+            depositor.key().as_ref(),
+        ],
+        bump = epoch_claim.bump,
+        has_one = depositor,
+        close = depositor,
+    )]
+    pub epoch_claim: Account<'info, EpochClaim>,
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
+    pub mint_account: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = depositor_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = depositor_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = depositor_pay_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_pay_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub vault_pay_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimEpochParams {
+    pub epoch_id: u64,
+}
+
+// Collects the bonds and quote tokens owed to a depositor from a settled epoch
+pub fn claim_epoch(ctx: Context<ClaimEpoch>, _params: ClaimEpochParams) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let user = &mut ctx.accounts.user;
+    let epoch = &ctx.accounts.epoch;
+    let epoch_claim = &ctx.accounts.epoch_claim;
+
+    require!(epoch.is_settled, VaultError::EpochNotSettled);
+
+    // a terminated vault never settles deposits again, so carried ones are handed back
+    let refund = if epoch.deposits_carried && vault.is_terminated {
+        epoch_claim.deposit_amount
+    } else {
+        0
+    };
+
+    // carried deposits are priced by the settlement that took them over
+    let deposit_epoch = if epoch.deposits_carried && epoch_claim.deposit_amount > 0 && refund == 0 {
+        let deposit_epoch = ctx
+            .accounts
+            .deposit_epoch
+            .as_ref()
+            .ok_or(VaultError::InvalidEpoch)?;
+        require!(deposit_epoch.is_settled, VaultError::EpochNotSettled);
+        require!(
+            !deposit_epoch.deposits_carried
+                && deposit_epoch.carried_deposits > 0
+                && deposit_epoch.carried_from <= epoch.epoch_id
+                && epoch.epoch_id < deposit_epoch.epoch_id,
+            VaultError::InvalidEpoch
+        );
+        deposit_epoch
+    } else {
+        epoch
+    };
+    let priced_deposits = deposit_epoch.total_deposits + deposit_epoch.carried_deposits;
+
+    // pro-rata shares of the epoch totals, rounded down in favour of the vault
+    let (bond_amount, deposit_value) = if priced_deposits > 0 && !deposit_epoch.deposits_carried {
+        (
+            mul_div(
+                epoch_claim.deposit_amount as u128,
+                deposit_epoch.bonds_minted as u128,
+                priced_deposits as u128,
+                Rounding::Down,
+            )? as u64,
+            mul_div(
+                epoch_claim.deposit_amount as u128,
+                deposit_epoch.net_deposits as u128,
+                priced_deposits as u128,
+                Rounding::Down,
+            )? as u64,
+        )
+    } else {
+        (0, 0)
+    };
+    let payout = if epoch.total_redemptions > 0 {
+        mul_div(
+            epoch_claim.redeem_bond_amount as u128,
+            epoch.net_payout as u128,
+            epoch.total_redemptions as u128,
+            Rounding::Down,
+        )? as u64
+    } else {
+        0
+    };
+
+    if bond_amount > 0 {
+        vault.mint_bonds(
+            vault_key,
+            ctx.accounts.mint_account.to_account_info(),
+            ctx.accounts.depositor_token_account.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            bond_amount,
+        )?;
+
+        user.bond_amount += bond_amount;
+        user.deposit_value += deposit_value;
+        user.deposit_time = deposit_epoch.settled_at;
+    }
+
+    if refund > 0 {
+        vault.transfer_tokens(
+            vault_key,
+            ctx.accounts.vault_pay_token_account.to_account_info(),
+            ctx.accounts.depositor_pay_token_account.to_account_info(),
+            ctx.accounts.vault_authority.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            refund,
+        )?;
+
+        vault.pending_deposits -= refund;
+        vault.carried_deposits -= refund;
+    }
+
+    if payout > 0 {
+        // settled redemptions are still withdrawals and wait out a withdrawal pause
+        vault.check_not_paused(PAUSE_WITHDRAWALS)?;
+        vault.transfer_tokens(
+            vault_key,
+            ctx.accounts.vault_pay_token_account.to_account_info(),
+            ctx.accounts.depositor_pay_token_account.to_account_info(),
+            ctx.accounts.vault_authority.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            payout,
+        )?;
+    }
+
+    Ok(())
+}
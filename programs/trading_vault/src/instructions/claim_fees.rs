@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{accounting::split_fee, error::*, ProtocolConfig, Vault};
+
+#[derive(Accounts)]
+pub struct ClaimFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = fee_manager,
+        has_one = fee_recipient @ VaultError::InvalidFeeRecipient,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    pub fee_manager: Signer<'info>,
+    #[account(mut)]
+    pub vault_pay_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub fee_recipient: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        token::mint = vault.quote_mint,
+        token::authority = protocol_config.treasury,
+    )]
+    pub protocol_treasury: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Pays out the accrued fees, split between the vault's fee recipient and the protocol treasury
+pub fn claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+
+    // fees routed to vault_pay itself would never leave it
+    require!(!vault.holds_fees(), VaultError::InvalidFeeRecipient);
+
+    vault.accrue_fees(Clock::get()?.unix_timestamp)?;
+
+    let fees = vault.accrued_fees;
+    let (leader_fee, protocol_fee) =
+        split_fee(fees, ctx.accounts.protocol_config.protocol_fee_bps)?;
+
+    vault.transfer_tokens(
+        vault_key,
+        ctx.accounts.vault_pay_token_account.to_account_info(),
+        ctx.accounts.fee_recipient.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        leader_fee,
+    )?;
+    vault.transfer_tokens(
+        vault_key,
+        ctx.accounts.vault_pay_token_account.to_account_info(),
+        ctx.accounts.protocol_treasury.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        protocol_fee,
+    )?;
+
+    vault.tvl -= fees;
+    vault.accrued_fees = 0;
+
+    vault.refresh_bond_price()?;
+
+    Ok(())
+}
@@ -1,99 +1,77 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+use anchor_spl::token::{Token, TokenAccount};
 
-use crate::{User, Vault, TOKEN_DECIMALS};
+use crate::{error::*, Position, ProtocolConfig, Vault};
 
 #[derive(Accounts)]
 pub struct ClosePosition<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.leader.key().as_ref()],
-        bump,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = backend_wallet,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
     )]
     pub vault: Account<'info, Vault>,
-    /// CHECK:
-    #[account(
-        seeds = [b"vault_authority"],
-        bump,
-        )]
-    pub vault_authority: AccountInfo<'info>,
-    pub leader: Signer<'info>,
-    /// CHECK:
     #[account(mut)]
-    pub depositor: AccountInfo<'info>,
+    pub backend_wallet: Signer<'info>,
     #[account(
-        seeds = [b"user", depositor.key().as_ref()],
-        bump,
+        mut,
+        seeds = [b"position", vault.key().as_ref(), position.market.as_ref()],
+        bump = position.bump,
+        has_one = vault @ VaultError::PositionMismatch,
+        close = backend_wallet,
     )]
-    pub user: Account<'info, User>,
+    pub position: Account<'info, Position>,
     #[account(mut)]
     pub vault_pay_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub depositor_pay_token_account: Account<'info, TokenAccount>,
-    // Mint account address is a PDA
     #[account(
         mut,
-        seeds = [b"mint"],
-        bump
+        constraint = backend_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = backend_pay_token_account.owner == backend_wallet.key() @ VaultError::InvalidTokenOwner,
     )]
-    pub mint_account: Account<'info, Mint>,
-    #[account(mut)]
-    pub depositor_token_account: Account<'info, TokenAccount>,
+    pub backend_pay_token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
 }
 
-// Closes all positions in the vault
-pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClosePositionParams {
+    // quote tokens the position was unwound for, returned to the vault by the backend
+    pub proceeds: u64,
+}
+
+// Removes a position the backend has closed and books its proceeds as idle cash in place of its equity
+pub fn close_position(ctx: Context<ClosePosition>, params: ClosePositionParams) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
     let vault = &mut ctx.accounts.vault;
-    let user = &mut ctx.accounts.user;
+    let equity = ctx.accounts.position.equity()?;
+
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
 
-    vault.transfer_tokens(
+    vault.transfer_tokens_from_user(
+        ctx.accounts.backend_pay_token_account.to_account_info(),
         ctx.accounts.vault_pay_token_account.to_account_info(),
-        ctx.accounts.depositor_pay_token_account.to_account_info(),
-        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.backend_wallet.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
-        user.deposit_value,
+        params.proceeds,
     )?;
 
-    let mut bond_amount = ctx.accounts.depositor_token_account.get_lamports();
-
-    if ctx.accounts.depositor.key() == vault.leader {
-        //  transfer performance fee
-        let performance_fee = ( vault.tvl - vault.deposit_value ) / 10;
-        vault.transfer_tokens(
-            ctx.accounts.vault_pay_token_account.to_account_info(),
-            ctx.accounts.depositor_pay_token_account.to_account_info(),
-            ctx.accounts.vault_authority.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            performance_fee
-        )?;
-
-        vault.tvl -= performance_fee;
-        bond_amount += (performance_fee / vault.bond_price) as u64 *10u64.pow(TOKEN_DECIMALS as u32);
-
-    }
-    // burn user's withdrawal bond amount
-    // PDA signer seeds
-    let signer_seeds: &[&[&[u8]]] = &[&[b"mint", &[ctx.bumps.mint_account]]];
-
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info().clone(),
-        Burn {
-            mint: ctx.accounts.mint_account.to_account_info(),
-            from: ctx.accounts.depositor_token_account.to_account_info(),
-            authority: ctx.accounts.mint_account.to_account_info(),
-        },
-        signer_seeds,
-    );
-    burn(cpi_ctx, bond_amount)?;
-
-    vault.deposit_value -= user.deposit_value;
-    vault.tvl -= user.deposit_value;
-    vault.bond_supply -= bond_amount;
-
-    user.deposit_value = 0;
+    vault.book_position_close(
+        vault_key,
+        equity,
+        params.proceeds,
+        Clock::get()?.unix_timestamp,
+    )?;
+    vault.position_count -= 1;
 
     Ok(())
 }
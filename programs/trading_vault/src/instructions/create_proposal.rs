@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, ParamChange, Proposal, ProposalAction, Vault, VoteLock};
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    // only holders with locked voting power may open a vote
+    #[account(
+        seeds = [b"vote_lock", vault.key().as_ref(), proposer.key().as_ref()],
+        bump = proposer_vote_lock.bump,
+    )]
+    pub proposer_vote_lock: Account<'info, VoteLock>,
+    #[account(
+        init,
+        seeds = [b"proposal", vault.key().as_ref(), vault.proposal_count.to_le_bytes().as_ref()],
+        bump,
+        payer = proposer,
+        space = Proposal::LEN
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateProposalParams {
+    pub action: ProposalAction,
+}
+
+// Opens a bond-holder vote on the given action, snapshotting the bond supply
+pub fn create_proposal(ctx: Context<CreateProposal>, params: CreateProposalParams) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!vault.is_terminated, VaultError::VaultTerminated);
+    require!(
+        ctx.accounts.proposer_vote_lock.amount > 0,
+        VaultError::NoVotingPower
+    );
+
+    if let ProposalAction::ChangeFees {
+        performance_fee_bps,
+        management_fee_bps,
+        entry_fee_bps,
+        exit_fee_bps,
+    } = params.action
+    {
+        ParamChange::Fees {
+            performance_fee_bps,
+            management_fee_bps,
+            entry_fee_bps,
+            exit_fee_bps,
+        }
+        .validate()?;
+    }
+
+    proposal.vault = vault.key();
+    proposal.index = vault.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = params.action;
+    proposal.snapshot_supply = vault.bond_supply;
+    proposal.created_at = current_time;
+    proposal.voting_ends_at = current_time.saturating_add(vault.governance_voting_period_secs);
+    proposal.yes_votes = 0;
+    proposal.no_votes = 0;
+    proposal.is_executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    vault.proposal_count += 1;
+
+    Ok(())
+}
@@ -1,35 +1,82 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{mint_to, Mint, MintTo, Token, TokenAccount},
+    token::{Mint, Token, TokenAccount},
 };
 
-use crate::{User, Vault, TOKEN_DECIMALS};
+use crate::{
+    accounting::{fee_amount, shares_for_assets, split_fee, Rounding},
+    error::*,
+    merkle, ProtocolConfig, User, Vault, PAUSE_DEPOSITS,
+};
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
+    )]
     pub vault: Account<'info, Vault>,
     /// CHECK:
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
     pub vault_authority: AccountInfo<'info>,
     #[account(mut)]
     pub depositor: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        init_if_needed,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump,
+        payer = depositor,
+        space = User::LEN
+    )]
     pub user: Account<'info, User>,
     // Mint account address is a PDA
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
     pub mint_account: Account<'info, Mint>,
     // payment token accounts for deposit
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = depositor_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = depositor_pay_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
     pub depositor_pay_token_account: Account<'info, TokenAccount>,
     #[account(mut)]
     pub vault_pay_token_account: Account<'info, TokenAccount>,
     // governance token accounts
     // Create Associated Token Account, if needed
     // This is the account that will hold the minted tokens
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = depositor_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
     pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = vault.fee_recipient @ VaultError::InvalidFeeRecipient,
+    )]
+    pub fee_recipient: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        token::mint = vault.quote_mint,
+        token::authority = protocol_config.treasury,
+    )]
+    pub protocol_treasury: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -38,52 +85,104 @@ pub struct Deposit<'info> {
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct DepositParams {
-    amount: u64, // in usd
+    amount: u64,          // in usd
+    proof: Vec<[u8; 32]>, // allowlist proof, only checked for private vaults
 }
 
 // Allows any user to deposit into the vault
 pub fn deposit(ctx: Context<Deposit>, params: DepositParams) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
     let vault = &mut ctx.accounts.vault;
     let user = &mut ctx.accounts.user;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!vault.uses_epochs(), VaultError::EpochPricingEnabled);
+    vault.check_not_paused(PAUSE_DEPOSITS)?;
+
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+
+    if vault.is_private {
+        require!(
+            merkle::verify(
+                &params.proof,
+                &vault.allowlist_root,
+                merkle::leaf(ctx.accounts.depositor.key().as_ref()),
+            ),
+            VaultError::NotAllowlisted
+        );
+    }
+
+    vault.accrue_fees(current_time)?;
+
+    let entry_fee = fee_amount(params.amount, vault.entry_fee_bps)?;
+    let net_amount = params.amount - entry_fee;
+    vault.check_deposit_caps(user.deposit_value, net_amount)?;
+
+    // bonds are priced before the deposit lands and rounded down in favour of the vault
+    let bond_amount = shares_for_assets(
+        net_amount,
+        vault.total_assets(),
+        vault.bond_supply,
+        Rounding::Down,
+    )?;
+    require!(bond_amount > 0, VaultError::ZeroBonds);
 
     vault.transfer_tokens_from_user(
         ctx.accounts.depositor_pay_token_account.to_account_info(),
         ctx.accounts.vault_pay_token_account.to_account_info(),
         ctx.accounts.depositor.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
-        params.amount,
+        net_amount,
     )?;
 
-    let bond_amount = (params.amount / vault.bond_price) as u64 * 10u64.pow(TOKEN_DECIMALS as u32);
-
-    // PDA signer seeds
-    let signer_seeds: &[&[&[u8]]] = &[&[b"mint", &[ctx.bumps.mint_account]]];
+    // entry fee is split between the leader's fee recipient and the protocol treasury
+    let (leader_fee, protocol_fee) =
+        split_fee(entry_fee, ctx.accounts.protocol_config.protocol_fee_bps)?;
+    if leader_fee > 0 {
+        vault.transfer_tokens_from_user(
+            ctx.accounts.depositor_pay_token_account.to_account_info(),
+            ctx.accounts.fee_recipient.to_account_info(),
+            ctx.accounts.depositor.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            leader_fee,
+        )?;
+        if vault.holds_fees() {
+            vault.keep_leader_fee(leader_fee)?;
+        }
+    }
+    if protocol_fee > 0 {
+        vault.transfer_tokens_from_user(
+            ctx.accounts.depositor_pay_token_account.to_account_info(),
+            ctx.accounts.protocol_treasury.to_account_info(),
+            ctx.accounts.depositor.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            protocol_fee,
+        )?;
+    }
 
     msg!(">>> mint token and assign it to depositor");
-    let _ = mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                authority: ctx.accounts.mint_account.to_account_info(),
-                to: ctx.accounts.depositor_token_account.to_account_info(),
-                mint: ctx.accounts.mint_account.to_account_info(),
-            },
-            signer_seeds,
-        ),
+    vault.mint_bonds(
+        vault_key,
+        ctx.accounts.mint_account.to_account_info(),
+        ctx.accounts.depositor_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
         bond_amount,
-    );
+    )?;
 
-    vault.tvl += params.amount;
-    vault.deposit_value += params.amount;
+    vault.tvl += net_amount;
+    vault.deposit_value += net_amount;
     vault.bond_supply += bond_amount;
 
-    user.deposit_value += params.amount;
+    user.deposit_value += net_amount;
     user.bond_amount += bond_amount;
-    user.deposit_time = Clock::get()?.unix_timestamp;
+    user.deposit_time = current_time;
+    user.bump = ctx.bumps.user;
 
     // recalculate bond price according to strategy
-    let profit = vault.tvl - vault.deposit_value;
-    vault.bond_price = (vault.deposit_value + profit * 80 / 100) / vault.bond_supply;
+    vault.refresh_bond_price()?;
 
     Ok(())
 }
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, ParamChange, PendingParamChange, Vault};
+
+#[derive(Accounts)]
+pub struct ExecuteParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: receives the change account's rent
+    #[account(mut)]
+    pub leader: AccountInfo<'info>,
+    // the leader executes its own changes, anyone may execute one approved by bond holders
+    #[account(
+        constraint = executor.key() == leader.key() || pending_param_change.by_governance @ VaultError::Unauthorized,
+    )]
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"param_change", vault.key().as_ref()],
+        bump = pending_param_change.bump,
+        close = leader,
+    )]
+    pub pending_param_change: Account<'info, PendingParamChange>,
+}
+
+// Applies the queued change of terms once its delay has passed
+pub fn execute_param_change(ctx: Context<ExecuteParamChange>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let pending_param_change = &ctx.accounts.pending_param_change;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        current_time >= pending_param_change.executable_at,
+        VaultError::ParamChangeDelayNotOver
+    );
+    vault.check_exit_window(
+        pending_param_change.queued_at,
+        pending_param_change.executable_at,
+        current_time,
+    )?;
+
+    match pending_param_change.change.clone() {
+        ParamChange::Fees {
+            performance_fee_bps,
+            management_fee_bps,
+            entry_fee_bps,
+            exit_fee_bps,
+        } => {
+            // fees owed up to now are charged at the old rates
+            vault.accrue_fees(current_time)?;
+            vault.refresh_bond_price()?;
+
+            vault.performance_fee_bps = performance_fee_bps;
+            vault.management_fee_bps = management_fee_bps;
+            vault.entry_fee_bps = entry_fee_bps;
+            vault.exit_fee_bps = exit_fee_bps;
+        }
+        ParamChange::LockPeriod { lock_period_secs } => vault.lock_period_secs = lock_period_secs,
+        ParamChange::Strategy { strategy_id } => vault.strategy_id = strategy_id,
+        ParamChange::BackendWallet { backend_wallet } => vault.backend_wallet = backend_wallet,
+        ParamChange::Terminate => vault.terminate(),
+    }
+
+    vault.param_change_executable_at = 0;
+
+    Ok(())
+}
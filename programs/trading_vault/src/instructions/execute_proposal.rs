@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    accounting::{mul_div, Rounding},
+    error::*,
+    ParamChange, PendingParamChange, Proposal, ProposalAction, Vault, BPS_DENOMINATOR,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = vault,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    // fee changes go through the same delay and exit window as the leader's own changes, and a
+    // passed proposal replaces whatever change the leader has queued
+    #[account(
+        init_if_needed,
+        seeds = [b"param_change", vault.key().as_ref()],
+        bump,
+        payer = executor,
+        space = PendingParamChange::LEN
+    )]
+    pub pending_param_change: Option<Account<'info, PendingParamChange>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Carries out a proposal that reached quorum and threshold once voting has ended
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let proposal = &mut ctx.accounts.proposal;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!proposal.is_executed, VaultError::ProposalAlreadyExecuted);
+    require!(!vault.is_terminated, VaultError::VaultTerminated);
+    require!(
+        current_time >= proposal.voting_ends_at,
+        VaultError::VotingNotOver
+    );
+
+    let votes_cast = proposal.yes_votes as u128 + proposal.no_votes as u128;
+    let quorum = mul_div(
+        proposal.snapshot_supply as u128,
+        vault.governance_quorum_bps as u128,
+        BPS_DENOMINATOR as u128,
+        Rounding::Up,
+    )?;
+    let threshold = mul_div(
+        votes_cast,
+        vault.governance_threshold_bps as u128,
+        BPS_DENOMINATOR as u128,
+        Rounding::Up,
+    )?;
+    require!(
+        votes_cast > 0 && votes_cast >= quorum && proposal.yes_votes as u128 >= threshold,
+        VaultError::ProposalNotPassed
+    );
+
+    match proposal.action {
+        ProposalAction::ReplaceLeader { new_leader } => vault.replace_leader(new_leader),
+        ProposalAction::ChangeFees {
+            performance_fee_bps,
+            management_fee_bps,
+            entry_fee_bps,
+            exit_fee_bps,
+        } => {
+            let pending_param_change = ctx
+                .accounts
+                .pending_param_change
+                .as_mut()
+                .ok_or(VaultError::MissingParamChangeAccount)?;
+            pending_param_change.queue(
+                vault_key,
+                vault,
+                ParamChange::Fees {
+                    performance_fee_bps,
+                    management_fee_bps,
+                    entry_fee_bps,
+                    exit_fee_bps,
+                },
+                true,
+                ctx.bumps.pending_param_change,
+                current_time,
+            )?;
+        }
+        ProposalAction::TerminateVault => vault.terminate(),
+    }
+
+    proposal.is_executed = true;
+
+    Ok(())
+}
@@ -0,0 +1,322 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+use anchor_spl::token::{self, TokenAccount};
+
+use crate::{error::*, oracle::OraclePrice, ProtocolConfig, Vault, PAUSE_TRADING};
+
+#[derive(Accounts)]
+pub struct ExecuteSwap<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = backend_wallet,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    pub backend_wallet: Signer<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        constraint = vault.is_vault_token_account(&source_token_account.key()) @ VaultError::InvalidSwapAccount,
+    )]
+    pub source_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = vault.is_vault_token_account(&destination_token_account.key()) @ VaultError::InvalidSwapAccount,
+        constraint = destination_token_account.key() != source_token_account.key() @ VaultError::InvalidSwapAccount,
+    )]
+    pub destination_token_account: Account<'info, TokenAccount>,
+    /// CHECK: checked against the protocol's allowed swap programs
+    #[account(
+        executable,
+        constraint = protocol_config.is_swap_program_allowed(&swap_program.key()) @ VaultError::SwapProgramNotAllowed,
+    )]
+    pub swap_program: AccountInfo<'info>,
+    /// CHECK: registered oracle of the source asset, required when swapping out of a custody account
+    pub source_oracle: Option<UncheckedAccount<'info>>,
+    /// CHECK: registered oracle of the destination asset, required when swapping into a custody account
+    pub destination_oracle: Option<UncheckedAccount<'info>>,
+    // remaining accounts: the accounts of the swap instruction, in the order the swap program expects
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteSwapParams {
+    pub amount_in: u64,
+    pub min_amount_out: u64,
+    // instruction data forwarded to the swap program
+    pub data: Vec<u8>,
+}
+
+// Swaps vault funds through an allowed DEX, signing as the vault authority, and books the trade into the NAV
+pub fn execute_swap<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteSwap<'info>>,
+    params: ExecuteSwapParams,
+) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // a terminated vault may only unwind its holdings into idle cash for the exiting depositors
+    if vault.is_terminated {
+        require_keys_eq!(
+            ctx.accounts.destination_token_account.key(),
+            vault.vault_pay_token_account,
+            VaultError::VaultTerminated
+        );
+    } else {
+        vault.check_not_paused(PAUSE_TRADING)?;
+    }
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+
+    check_swap_accounts(
+        ctx.remaining_accounts,
+        &ctx.accounts.vault_authority.key(),
+        &[
+            ctx.accounts.source_token_account.key(),
+            ctx.accounts.destination_token_account.key(),
+        ],
+    )?;
+
+    let source_before = ctx.accounts.source_token_account.amount;
+    let destination_before = ctx.accounts.destination_token_account.amount;
+
+    // the vault authority signs through the PDA seeds, every other signer must sign the transaction
+    let vault_authority = ctx.accounts.vault_authority.key();
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == vault_authority,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let instruction = Instruction {
+        program_id: ctx.accounts.swap_program.key(),
+        accounts,
+        data: params.data,
+    };
+
+    let authority_seeds: &[&[&[u8]]] = &[&[
+        b"vault_authority",
+        vault_key.as_ref(),
+        &[vault.vault_authority_bump],
+    ]];
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.swap_program.to_account_info());
+    invoke_signed(&instruction, &account_infos, authority_seeds)?;
+
+    ctx.accounts.source_token_account.reload()?;
+    ctx.accounts.destination_token_account.reload()?;
+
+    // the swap program must not have taken over or delegated either vault account
+    for token_account in [
+        &ctx.accounts.source_token_account,
+        &ctx.accounts.destination_token_account,
+    ] {
+        require_keys_eq!(
+            token_account.owner,
+            vault_authority,
+            VaultError::SwapAccountTampered
+        );
+        require!(
+            token_account.delegate.is_none() && token_account.close_authority.is_none(),
+            VaultError::SwapAccountTampered
+        );
+    }
+
+    let spent = source_before.saturating_sub(ctx.accounts.source_token_account.amount);
+    let received = ctx
+        .accounts
+        .destination_token_account
+        .amount
+        .saturating_sub(destination_before);
+    require!(spent <= params.amount_in, VaultError::SwapInputExceeded);
+    require!(
+        received >= params.min_amount_out,
+        VaultError::SlippageExceeded
+    );
+
+    let (spent_value, spent_from_custody) = swap_leg_value(
+        vault,
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.source_token_account.key(),
+        ctx.accounts.source_oracle.as_ref(),
+        spent,
+        current_time,
+    )?;
+    let (received_value, received_into_custody) = swap_leg_value(
+        vault,
+        &ctx.accounts.protocol_config,
+        &ctx.accounts.destination_token_account.key(),
+        ctx.accounts.destination_oracle.as_ref(),
+        received,
+        current_time,
+    )?;
+
+    ctx.accounts.vault.book_swap(
+        vault_key,
+        spent_value,
+        spent_from_custody,
+        received_value,
+        received_into_custody,
+        current_time,
+    )?;
+
+    msg!("Swapped {} for {}", spent, received);
+
+    Ok(())
+}
+
+// The swap runs with the vault authority's signature, so it must not reach any vault-held
+// token account (other custodies, the bond escrow) besides the two legs being traded
+fn check_swap_accounts(
+    remaining_accounts: &[AccountInfo],
+    vault_authority: &Pubkey,
+    swap_legs: &[Pubkey],
+) -> Result<()> {
+    for account in remaining_accounts {
+        if swap_legs.contains(account.key) || *account.owner != token::ID {
+            continue;
+        }
+        if let Ok(token_account) =
+            TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])
+        {
+            require_keys_neq!(
+                token_account.owner,
+                *vault_authority,
+                VaultError::InvalidSwapAccount
+            );
+        }
+    }
+    Ok(())
+}
+
+// Quote value of `amount` moved through one leg of a swap, and whether that leg is a custody account.
+// The pay account holds the quote token itself; custody assets are valued with their registered oracle.
+fn swap_leg_value(
+    vault: &Vault,
+    protocol_config: &ProtocolConfig,
+    token_account: &Pubkey,
+    oracle: Option<&UncheckedAccount>,
+    amount: u64,
+    current_time: i64,
+) -> Result<(u64, bool)> {
+    let Some(asset) = vault.asset_by_custody(token_account) else {
+        return Ok((amount, false));
+    };
+
+    let oracle = oracle.ok_or(VaultError::OracleMismatch)?;
+    require_keys_eq!(oracle.key(), asset.oracle, VaultError::OracleMismatch);
+
+    let price = OraclePrice::load(oracle, &protocol_config.oracle_program)?;
+    price.validate(
+        current_time,
+        vault.oracle_max_staleness_secs,
+        vault.oracle_max_conf_bps,
+    )?;
+    Ok((
+        price.value_of(amount, asset.decimals, vault.quote_decimals)?,
+        true,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_spl::token::spl_token::state::{Account as SplAccount, AccountState};
+
+    fn token_account_data(owner: Pubkey) -> Vec<u8> {
+        let mut data = vec![0u8; SplAccount::LEN];
+        SplAccount {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount: 1_000,
+            state: AccountState::Initialized,
+            ..SplAccount::default()
+        }
+        .pack_into_slice(&mut data);
+        data
+    }
+
+    fn check(accounts: &mut [(Pubkey, Pubkey, Vec<u8>)], vault_authority: &Pubkey) -> Result<()> {
+        let mut lamports: Vec<u64> = vec![0; accounts.len()];
+        let swap_legs = [accounts[0].0, accounts[1].0];
+        let infos: Vec<AccountInfo> = accounts
+            .iter_mut()
+            .zip(lamports.iter_mut())
+            .map(|((key, owner, data), lamports)| {
+                AccountInfo::new(key, false, true, lamports, data, owner, false, 0)
+            })
+            .collect();
+        check_swap_accounts(&infos, vault_authority, &swap_legs)
+    }
+
+    #[test]
+    fn allows_the_swap_legs_and_foreign_token_accounts() {
+        let vault_authority = Pubkey::new_unique();
+        let mut accounts = vec![
+            (
+                Pubkey::new_unique(),
+                token::ID,
+                token_account_data(vault_authority),
+            ),
+            (
+                Pubkey::new_unique(),
+                token::ID,
+                token_account_data(vault_authority),
+            ),
+            (
+                Pubkey::new_unique(),
+                token::ID,
+                token_account_data(Pubkey::new_unique()),
+            ),
+            (Pubkey::new_unique(), Pubkey::new_unique(), vec![0u8; 64]),
+        ];
+        assert!(check(&mut accounts, &vault_authority).is_ok());
+    }
+
+    #[test]
+    fn rejects_other_vault_held_token_accounts() {
+        let vault_authority = Pubkey::new_unique();
+        let mut accounts = vec![
+            (
+                Pubkey::new_unique(),
+                token::ID,
+                token_account_data(vault_authority),
+            ),
+            (
+                Pubkey::new_unique(),
+                token::ID,
+                token_account_data(vault_authority),
+            ),
+            // e.g. the bond escrow or a custody not taking part in the swap
+            (
+                Pubkey::new_unique(),
+                token::ID,
+                token_account_data(vault_authority),
+            ),
+        ];
+        assert_eq!(
+            check(&mut accounts, &vault_authority).unwrap_err(),
+            VaultError::InvalidSwapAccount.into()
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    accounting::{assets_for_shares, fee_amount, Rounding},
+    error::*,
+    ExitFeeAccounts, ProtocolConfig, User, Vault, PAUSE_WITHDRAWALS,
+};
+
+#[derive(Accounts)]
+pub struct ForceRedeem<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+        )]
+    pub vault_authority: AccountInfo<'info>,
+    pub leader: Signer<'info>,
+    // co-signs as the owner of the bonds being burnt
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = user.bump,
+    )]
+    pub user: Account<'info, User>,
+    #[account(mut)]
+    pub vault_pay_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = depositor_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = depositor_pay_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_pay_token_account: Account<'info, TokenAccount>,
+    // Mint account address is a PDA
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
+    pub mint_account: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = depositor_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = vault.fee_recipient @ VaultError::InvalidFeeRecipient,
+    )]
+    pub fee_recipient: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        token::mint = vault.quote_mint,
+        token::authority = protocol_config.treasury,
+    )]
+    pub protocol_treasury: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+// Redeems a depositor's whole position at the current NAV at the leader's request
+pub fn force_redeem(ctx: Context<ForceRedeem>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let user = &mut ctx.accounts.user;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!vault.uses_epochs(), VaultError::EpochPricingEnabled);
+    // exits from a terminated vault are priced at its realised cash, which only the holder may choose
+    require!(!vault.is_terminated, VaultError::VaultTerminated);
+    vault.check_not_paused(PAUSE_WITHDRAWALS)?;
+    require!(
+        !vault.is_locked(user.deposit_time, current_time),
+        VaultError::LockPeriodNotOver
+    );
+
+    vault.accrue_fees(current_time)?;
+
+    // the whole position is redeemed at the current NAV, rounded down in favour of the vault
+    let bond_amount = user.bond_amount;
+    let payout = assets_for_shares(
+        bond_amount,
+        vault.total_assets(),
+        vault.bond_supply,
+        Rounding::Down,
+    )?;
+
+    // a forced exit is charged the same exit fee as a voluntary withdrawal
+    let exit_fee = fee_amount(payout, vault.exit_fee_bps)?;
+
+    vault.transfer_tokens(
+        vault_key,
+        ctx.accounts.vault_pay_token_account.to_account_info(),
+        ctx.accounts.depositor_pay_token_account.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        payout - exit_fee,
+    )?;
+
+    vault.pay_exit_fee(
+        vault_key,
+        ExitFeeAccounts {
+            vault_pay_token_account: ctx.accounts.vault_pay_token_account.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            vault_authority: ctx.accounts.vault_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        exit_fee,
+        ctx.accounts.protocol_config.protocol_fee_bps,
+    )?;
+
+    // burn user's withdrawal bond amount
+    vault.burn_bonds(
+        vault_key,
+        ctx.accounts.mint_account.to_account_info(),
+        ctx.accounts.depositor_token_account.to_account_info(),
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        bond_amount,
+    )?;
+
+    vault.deposit_value -= user.deposit_value;
+    vault.tvl -= payout;
+    vault.bond_supply -= bond_amount;
+
+    user.deposit_value = 0;
+    user.bond_amount = 0;
+
+    vault.refresh_bond_price()?;
+
+    Ok(())
+}
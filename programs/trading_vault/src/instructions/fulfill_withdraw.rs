@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    accounting::{assets_for_shares, fee_amount, Rounding},
+    error::*,
+    ExitFeeAccounts, ProtocolConfig, Vault, WithdrawRequest, PAUSE_WITHDRAWALS,
+};
+
+#[derive(Accounts)]
+pub struct FulfillWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = backend_wallet,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    pub backend_wallet: Signer<'info>,
+    /// CHECK: receives the request account's rent
+    #[account(mut)]
+    pub depositor: AccountInfo<'info>,
+    #[account(
+        mut,
+        seeds = [b"withdraw_request", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = withdraw_request.bump,
+        has_one = depositor,
+        close = depositor,
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
+    pub mint_account: Account<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"bond_escrow", vault.key().as_ref()],
+        bump,
+    )]
+    pub bond_escrow: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub vault_pay_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = depositor_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = depositor_pay_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_pay_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = vault.fee_recipient @ VaultError::InvalidFeeRecipient,
+    )]
+    pub fee_recipient: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        token::mint = vault.quote_mint,
+        token::authority = protocol_config.treasury,
+    )]
+    pub protocol_treasury: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// Pays out an escrowed withdraw request at the NAV at fulfilment
+pub fn fulfill_withdraw(ctx: Context<FulfillWithdraw>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let withdraw_request = &ctx.accounts.withdraw_request;
+
+    vault.check_not_paused(PAUSE_WITHDRAWALS)?;
+
+    vault.accrue_fees(Clock::get()?.unix_timestamp)?;
+
+    let payout = assets_for_shares(
+        withdraw_request.bond_amount,
+        vault.redeemable_assets(),
+        vault.bond_supply,
+        Rounding::Down,
+    )?;
+    let exit_fee = fee_amount(payout, vault.exit_fee_bps)?;
+
+    vault.transfer_tokens(
+        vault_key,
+        ctx.accounts.vault_pay_token_account.to_account_info(),
+        ctx.accounts.depositor_pay_token_account.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        payout - exit_fee,
+    )?;
+
+    vault.pay_exit_fee(
+        vault_key,
+        ExitFeeAccounts {
+            vault_pay_token_account: ctx.accounts.vault_pay_token_account.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            vault_authority: ctx.accounts.vault_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        exit_fee,
+        ctx.accounts.protocol_config.protocol_fee_bps,
+    )?;
+
+    vault.burn_bonds(
+        vault_key,
+        ctx.accounts.mint_account.to_account_info(),
+        ctx.accounts.bond_escrow.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        withdraw_request.bond_amount,
+    )?;
+
+    vault.tvl -= payout;
+    vault.deposit_value -= withdraw_request.deposit_value;
+    vault.bond_supply -= withdraw_request.bond_amount;
+
+    vault.refresh_bond_price()?;
+
+    Ok(())
+}
@@ -1,38 +1,76 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::{constants::TOKEN_DECIMALS, error::*, User, Vault};
+use crate::{
+    accounting::{shares_for_assets, Rounding},
+    error::*,
+    ProtocolConfig, User, Vault, PAUSE_DEPOSITS,
+};
 
 #[derive(Accounts)]
 pub struct InitDeposit<'info> {
     #[account(mut)]
     pub leader: Signer<'info>,
-    #[account(mut)]
+    #[account(
+        init_if_needed,
+        seeds = [b"user", vault.key().as_ref(), leader.key().as_ref()],
+        bump,
+        payer = leader,
+        space = User::LEN
+    )]
     pub user: Account<'info, User>,
 
     #[account(mut)]
     pub backend_wallet: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+        has_one = backend_wallet,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
+    )]
     pub vault: Account<'info, Vault>,
     /// CHECK:
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
     pub vault_authority: AccountInfo<'info>,
 
     // Create mint account
     // Same PDA as address of the account and mint/freeze authority
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
     pub mint_account: Account<'info, Mint>,
 
     #[account(mut)]
     pub vault_pay_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = leader_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = leader_pay_token_account.owner == leader.key() @ VaultError::InvalidTokenOwner,
+    )]
     pub leader_pay_token_account: Account<'info, TokenAccount>,
     // Create Associated Token Account, if needed
     // This is the account that will hold the minted tokens
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = leader_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = leader_token_account.owner == leader.key() @ VaultError::InvalidTokenOwner,
+    )]
     pub leader_token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
@@ -41,46 +79,75 @@ pub struct InitDeposit<'info> {
 }
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitDepositParams {
-    pub strategy_id: String,
     pub initial_deposit: u64,
 }
 
-// Initializes the vault with the first depositor as the leader
+// Seeds an empty vault with the leader's first deposit
 pub fn init_deposit(
     ctx: Context<InitDeposit>,
     params: InitDepositParams,
 ) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
     let vault = &mut ctx.accounts.vault;
     let leader = &mut ctx.accounts.leader;
     let user = &mut ctx.accounts.user;
+    let current_time = Clock::get()?.unix_timestamp;
 
+    require!(!vault.uses_epochs(), VaultError::EpochPricingEnabled);
+    vault.check_not_paused(PAUSE_DEPOSITS)?;
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+    // one-shot: later deposits go through `deposit` so existing holders are never overwritten
+    require!(
+        vault.bond_supply == 0 && vault.tvl == 0,
+        VaultError::VaultAlreadyFunded
+    );
     require!(
         params.initial_deposit >= 10 * 1_000_000,
         VaultError::InsufficientDeposit
     ); // 10 USD assuming 6 decimal places
 
-    vault.strategy_id = params.strategy_id;
-    vault.deposit_value = params.initial_deposit;
-    vault.tvl = params.initial_deposit;
-    vault.leader = *leader.to_account_info().key;
+    vault.check_deposit_caps(user.deposit_value, params.initial_deposit)?;
+
+    // the vault sat empty since initialization, so the fee clock starts with the seed deposit
+    vault.accrue_fees(current_time)?;
+
+    let bond_amount = shares_for_assets(
+        params.initial_deposit,
+        vault.total_assets(),
+        vault.bond_supply,
+        Rounding::Down,
+    )?;
+    require!(bond_amount > 0, VaultError::ZeroBonds);
+
+    vault.deposit_value += params.initial_deposit;
+    vault.tvl += params.initial_deposit;
 
     vault.transfer_tokens_from_user(
         ctx.accounts.leader_pay_token_account.to_account_info(),
         ctx.accounts.vault_pay_token_account.to_account_info(),
-        ctx.accounts.vault_authority.to_account_info(),
+        leader.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
         params.initial_deposit,
     )?;
 
-    let bond_amount =
-        params.initial_deposit / 1_000_000 * 10u64.pow(ctx.accounts.mint_account.decimals as u32);
+    vault.mint_bonds(
+        vault_key,
+        ctx.accounts.mint_account.to_account_info(),
+        ctx.accounts.leader_token_account.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        bond_amount,
+    )?;
 
-    user.deposit_value = params.initial_deposit;
-    user.bond_amount = bond_amount;
-    vault.bond_supply = bond_amount;
-    user.deposit_time = Clock::get()?.unix_timestamp;
+    user.deposit_value += params.initial_deposit;
+    user.bond_amount += bond_amount;
+    vault.bond_supply += bond_amount;
+    user.deposit_time = current_time;
+    user.bump = ctx.bumps.user;
 
-    vault.bond_price = vault.tvl / vault.bond_supply;
+    vault.refresh_bond_price()?;
 
     Ok(())
 }
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, program::TradingVault, ProtocolConfig};
+
+#[derive(Accounts)]
+pub struct InitializeProtocol<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"protocol_config"],
+        bump,
+        payer = admin,
+        space = ProtocolConfig::LEN
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    // only the program's upgrade authority may become the protocol admin
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, TradingVault>,
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ VaultError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProtocolConfigParams {
+    pub treasury: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub allowed_quote_mints: Vec<Pubkey>,
+    pub allowed_swap_programs: Vec<Pubkey>,
+    pub oracle_program: Pubkey,
+    pub is_paused: bool,
+}
+
+// Creates the global protocol configuration
+pub fn initialize_protocol(
+    ctx: Context<InitializeProtocol>,
+    params: ProtocolConfigParams,
+) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+
+    protocol_config.admin = ctx.accounts.admin.key();
+    protocol_config.bump = ctx.bumps.protocol_config;
+    protocol_config.apply(params)
+}
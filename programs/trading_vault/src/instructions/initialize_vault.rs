@@ -1,10 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token};
-use anchor_spl::metadata::{create_metadata_accounts_v3, mpl_token_metadata::types::DataV2,
-    CreateMetadataAccountsV3, Metadata};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::metadata::{
+    create_metadata_accounts_v3,
+    mpl_token_metadata::{types::DataV2, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH},
+    CreateMetadataAccountsV3, Metadata,
+};
 
-use crate::{constants::TOKEN_DECIMALS, Vault};
+use crate::{
+    accounting,
+    constants::{
+        BPS_DENOMINATOR, MAX_ENTRY_EXIT_FEE_BPS, MAX_MANAGEMENT_FEE_BPS, MAX_PERFORMANCE_FEE_BPS,
+        MAX_STRATEGY_ID_LEN, TOKEN_DECIMALS,
+    },
+    error::*,
+    LeaderProfile, ProtocolConfig, Vault,
+};
 
 #[derive(Accounts)]
 pub struct InitializeVault<'info> {
@@ -14,18 +25,68 @@ pub struct InitializeVault<'info> {
     #[account(mut)]
     pub backend_wallet: Signer<'info>,
 
-    #[account(mut)]
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        seeds = [b"leader_profile", leader.key().as_ref()],
+        bump,
+        payer = leader,
+        space = LeaderProfile::LEN
+    )]
+    pub leader_profile: Account<'info, LeaderProfile>,
+
+    #[account(
+        init,
+        seeds = [b"vault", leader.key().as_ref(), leader_profile.vault_count.to_le_bytes().as_ref()],
+        bump,
+        payer = leader,
+        space = Vault::LEN
+    )]
     pub vault: Account<'info, Vault>,
     /// CHECK:
-    #[account(mut)]
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump,
+    )]
     pub vault_authority: AccountInfo<'info>,
 
     // Create mint account
     // Same PDA as address of the account and mint/freeze authority
-    #[account(mut)]
+    #[account(
+        init,
+        seeds = [b"mint", vault.key().as_ref()],
+        bump,
+        payer = leader,
+        mint::decimals = TOKEN_DECIMALS,
+        mint::authority = mint_account.key(),
+        mint::freeze_authority = mint_account.key(),
+    )]
     pub mint_account: Account<'info, Mint>,
+    // token deposited into and paid out of the vault
+    pub quote_mint: Account<'info, Mint>,
+    #[account(
+        init,
+        seeds = [b"vault_pay", vault.key().as_ref()],
+        bump,
+        payer = leader,
+        token::mint = quote_mint,
+        token::authority = vault_authority,
+    )]
+    pub vault_pay_token_account: Account<'info, TokenAccount>,
+    #[account(token::mint = quote_mint)]
+    pub fee_recipient: Account<'info, TokenAccount>,
     /// CHECK: Validate address by deriving pda
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint_account.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
     pub metadata_account: UncheckedAccount<'info>,
         
     pub system_program: Program<'info, System>,
@@ -35,24 +96,199 @@ pub struct InitializeVault<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeVaultParams {
+    pub strategy_id: String,
+    pub lock_period_secs: i64,
+    pub max_tvl: u64,
+    pub max_deposit_per_user: u64,
+    pub epoch_length_secs: i64,
+    pub performance_fee_bps: u16,
+    pub management_fee_bps: u16,
+    pub entry_fee_bps: u16,
+    pub exit_fee_bps: u16,
+    pub max_nav_change_bps: u16,
+    pub min_nav_report_interval_secs: i64,
+    pub max_drawdown_bps: u16,
+    pub param_change_delay_secs: i64,
+    pub governance_quorum_bps: u16,
+    pub governance_threshold_bps: u16,
+    pub governance_voting_period_secs: i64,
+    pub drawdown_cooldown_secs: i64,
+    pub oracle_max_staleness_secs: i64,
+    pub oracle_max_conf_bps: u16,
+    // token metadata of the vault's bond mint
+    pub bond_name: String,
+    pub bond_symbol: String,
+    pub bond_uri: String,
+}
+
 // Initializes the vault with the first depositor as the leader
 pub fn initialize_vault(
     ctx: Context<InitializeVault>,
+    params: InitializeVaultParams,
 ) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
     let leader = &mut ctx.accounts.leader;
+    let leader_profile = &mut ctx.accounts.leader_profile;
+    let protocol_config = &ctx.accounts.protocol_config;
+
+    require!(!protocol_config.is_paused, VaultError::ProtocolPaused);
+    require!(
+        protocol_config.is_quote_mint_allowed(&ctx.accounts.quote_mint.key()),
+        VaultError::QuoteMintNotAllowed
+    );
+    require!(
+        params.strategy_id.len() <= MAX_STRATEGY_ID_LEN,
+        VaultError::StrategyIdTooLong
+    );
+    require!(
+        params.bond_name.len() <= MAX_NAME_LENGTH
+            && params.bond_symbol.len() <= MAX_SYMBOL_LENGTH
+            && params.bond_uri.len() <= MAX_URI_LENGTH,
+        VaultError::InvalidBondMetadata
+    );
+    require!(params.lock_period_secs >= 0, VaultError::InvalidLockPeriod);
+    require!(params.epoch_length_secs >= 0, VaultError::InvalidEpochLength);
+    require!(
+        params.min_nav_report_interval_secs >= 0,
+        VaultError::InvalidNavReportInterval
+    );
+    require!(
+        params.max_drawdown_bps as u64 <= BPS_DENOMINATOR,
+        VaultError::InvalidDrawdownLimit
+    );
+    // a proposal needs a non-zero quorum and a strict majority of the votes cast
+    require!(
+        params.governance_quorum_bps > 0
+            && params.governance_quorum_bps as u64 <= BPS_DENOMINATOR
+            && params.governance_threshold_bps as u64 > BPS_DENOMINATOR / 2
+            && params.governance_threshold_bps as u64 <= BPS_DENOMINATOR
+            && params.governance_voting_period_secs >= 0,
+        VaultError::InvalidGovernanceParams
+    );
+    require!(
+        params.param_change_delay_secs >= 0,
+        VaultError::InvalidParamChangeDelay
+    );
+    require!(
+        params.drawdown_cooldown_secs >= 0,
+        VaultError::InvalidDrawdownCooldown
+    );
+    require!(
+        params.performance_fee_bps <= MAX_PERFORMANCE_FEE_BPS,
+        VaultError::InvalidFeeRate
+    );
+    require!(
+        params.management_fee_bps <= MAX_MANAGEMENT_FEE_BPS,
+        VaultError::InvalidFeeRate
+    );
+    require!(
+        params.entry_fee_bps <= MAX_ENTRY_EXIT_FEE_BPS
+            && params.exit_fee_bps <= MAX_ENTRY_EXIT_FEE_BPS,
+        VaultError::InvalidFeeRate
+    );
+
+    leader_profile.leader = leader.key();
+    leader_profile.bump = ctx.bumps.leader_profile;
+    vault.index = leader_profile.vault_count;
+    leader_profile.vault_count += 1;
 
     vault.vault_authority = ctx.accounts.vault_authority.key();
     vault.vault_authority_bump = ctx.bumps.vault_authority;
+    vault.quote_mint = ctx.accounts.quote_mint.key();
+    vault.quote_decimals = ctx.accounts.quote_mint.decimals;
+    vault.vault_pay_token_account = ctx.accounts.vault_pay_token_account.key();
+    vault.bond_mint = ctx.accounts.mint_account.key();
+    vault.mint_bump = ctx.bumps.mint_account;
+    vault.bump = ctx.bumps.vault;
     vault.backend_wallet = ctx.accounts.backend_wallet.key();
-    vault.strategy_id = "".to_owned();
-    vault.bond_price = 1 * 1_000_000;
+    vault.position_equity = 0;
+    vault.position_count = 0;
+    vault.last_nav_report_ts = 0;
+    vault.positions_value = 0;
+    vault.last_positions_sync_ts = 0;
+    vault.max_nav_change_bps = params.max_nav_change_bps;
+    vault.min_nav_report_interval_secs = params.min_nav_report_interval_secs;
+    vault.max_drawdown_bps = params.max_drawdown_bps;
+    vault.peak_nav = accounting::NAV_PRECISION;
+    vault.drawdown_cooldown_secs = params.drawdown_cooldown_secs;
+    vault.drawdown_tripped_at = 0;
+    vault.asset_count = 0;
+    vault.holdings_value = 0;
+    vault.last_holdings_refresh_ts = 0;
+    vault.oracle_max_staleness_secs = params.oracle_max_staleness_secs;
+    vault.oracle_max_conf_bps = params.oracle_max_conf_bps;
+    vault.strategy_id = params.strategy_id;
+    vault.bond_price = accounting::bond_price(0, 0)?;
     vault.deposit_value = 0;
     vault.tvl = 0;
     vault.leader = *leader.to_account_info().key;
-    vault.is_trading_paused = false;
+    vault.creator = leader.key();
+    vault.pending_leader = Pubkey::default();
+    vault.pause_flags = 0;
+    vault.guardian = Pubkey::default();
+    vault.lock_period_secs = params.lock_period_secs;
+    vault.param_change_delay_secs = params.param_change_delay_secs;
+    vault.param_change_executable_at = 0;
+    vault.withdrawals_resumed_at = 0;
+    vault.is_terminated = false;
+    vault.governance_quorum_bps = params.governance_quorum_bps;
+    vault.governance_threshold_bps = params.governance_threshold_bps;
+    vault.governance_voting_period_secs = params.governance_voting_period_secs;
+    vault.proposal_count = 0;
+    vault.max_tvl = params.max_tvl;
+    vault.max_deposit_per_user = params.max_deposit_per_user;
+    vault.is_private = false;
+    vault.allowlist_root = [0u8; 32];
+    vault.epoch_length_secs = params.epoch_length_secs;
+    vault.current_epoch = 0;
+    vault.epoch_started_at = Clock::get()?.unix_timestamp;
+    vault.pending_deposits = 0;
+    vault.carried_deposits = 0;
+    vault.carried_from_epoch = 0;
+    vault.performance_fee_bps = params.performance_fee_bps;
+    vault.high_water_mark = accounting::NAV_PRECISION;
+    vault.management_fee_bps = params.management_fee_bps;
+    vault.last_fee_accrual_ts = Clock::get()?.unix_timestamp;
+    vault.accrued_fees = 0;
+    vault.entry_fee_bps = params.entry_fee_bps;
+    vault.exit_fee_bps = params.exit_fee_bps;
+    vault.fee_recipient = ctx.accounts.fee_recipient.key();
+    vault.fee_manager = leader.key();
 
     msg!("Creating metadata account");
-    
+
+    // the mint PDA is both mint and update authority, so only the program can ever change it
+    let vault_key = vault.key();
+    let mint_seeds: &[&[&[u8]]] = &[&[b"mint", vault_key.as_ref(), &[vault.mint_bump]]];
+    create_metadata_accounts_v3(
+        CpiContext::new(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata_account.to_account_info(),
+                mint: ctx.accounts.mint_account.to_account_info(),
+                mint_authority: ctx.accounts.mint_account.to_account_info(),
+                payer: leader.to_account_info(),
+                update_authority: ctx.accounts.mint_account.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+        )
+        .with_signer(mint_seeds),
+        DataV2 {
+            name: params.bond_name,
+            symbol: params.bond_symbol,
+            uri: params.bond_uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        true,
+        true,
+        None,
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{error::*, Vault, VoteLock};
+
+#[derive(Accounts)]
+pub struct LockBonds<'info> {
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [b"vote_lock", vault.key().as_ref(), owner.key().as_ref()],
+        bump,
+        payer = owner,
+        space = VoteLock::LEN
+    )]
+    pub vote_lock: Account<'info, VoteLock>,
+    #[account(
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
+    pub mint_account: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = owner_token_account.owner == owner.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    // Escrow holding every holder's locked bonds
+    #[account(
+        init_if_needed,
+        seeds = [b"vote_lock_escrow", vault.key().as_ref()],
+        bump,
+        payer = owner,
+        token::mint = mint_account,
+        token::authority = vault_authority,
+    )]
+    pub vote_lock_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LockBondsParams {
+    pub amount: u64,
+}
+
+// Locks bonds as voting power for proposals created from now on
+pub fn lock_bonds(ctx: Context<LockBonds>, params: LockBondsParams) -> Result<()> {
+    require!(params.amount > 0, VaultError::InsufficientFunds);
+
+    ctx.accounts.vault.transfer_tokens_from_user(
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.vote_lock_escrow.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        params.amount,
+    )?;
+
+    let vote_lock = &mut ctx.accounts.vote_lock;
+    vote_lock.vault = ctx.accounts.vault.key();
+    vote_lock.owner = ctx.accounts.owner.key();
+    vote_lock.amount = vote_lock
+        .amount
+        .checked_add(params.amount)
+        .ok_or(VaultError::MathOverflow)?;
+    vote_lock.last_locked_at = Clock::get()?.unix_timestamp;
+    vote_lock.bump = ctx.bumps.vote_lock;
+
+    Ok(())
+}
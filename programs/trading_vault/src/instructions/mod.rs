@@ -1,3 +1,9 @@
+pub mod initialize_protocol;
+pub use initialize_protocol::*;
+
+pub mod update_protocol_config;
+pub use update_protocol_config::*;
+
 pub mod initialize_vault;
 pub use initialize_vault::*;
 
@@ -19,5 +25,107 @@ pub use pause_trading::*;
 pub mod terminate_vault;
 pub use terminate_vault::*;
 
+pub mod force_redeem;
+pub use force_redeem::*;
+
+pub mod update_lock_period;
+pub use update_lock_period::*;
+
+pub mod claim_fees;
+pub use claim_fees::*;
+
+pub mod accrue_fees;
+pub use accrue_fees::*;
+
+pub mod set_fee_recipient;
+pub use set_fee_recipient::*;
+
+pub mod report_nav;
+pub use report_nav::*;
+
+pub mod register_asset;
+pub use register_asset::*;
+
+pub mod refresh_holdings;
+pub use refresh_holdings::*;
+
+pub mod update_deposit_caps;
+pub use update_deposit_caps::*;
+
+pub mod update_allowlist;
+pub use update_allowlist::*;
+
+pub mod request_withdraw;
+pub use request_withdraw::*;
+
+pub mod fulfill_withdraw;
+pub use fulfill_withdraw::*;
+
+pub mod cancel_withdraw;
+pub use cancel_withdraw::*;
+
+pub mod queue_deposit;
+pub use queue_deposit::*;
+
+pub mod queue_redeem;
+pub use queue_redeem::*;
+
+pub mod settle_epoch;
+pub use settle_epoch::*;
+
+pub mod claim_epoch;
+pub use claim_epoch::*;
+
+pub mod execute_swap;
+pub use execute_swap::*;
+
+pub mod open_position;
+pub use open_position::*;
+
+pub mod update_position;
+pub use update_position::*;
+
 pub mod close_position;
 pub use close_position::*;
+
+pub mod sync_positions;
+pub use sync_positions::*;
+
+pub mod set_role;
+pub use set_role::*;
+
+pub mod propose_leader;
+pub use propose_leader::*;
+
+pub mod accept_leader;
+pub use accept_leader::*;
+
+pub mod revoke_backend_wallet;
+pub use revoke_backend_wallet::*;
+
+pub mod queue_param_change;
+pub use queue_param_change::*;
+
+pub mod execute_param_change;
+pub use execute_param_change::*;
+
+pub mod cancel_param_change;
+pub use cancel_param_change::*;
+
+pub mod create_proposal;
+pub use create_proposal::*;
+
+pub mod lock_bonds;
+pub use lock_bonds::*;
+
+pub mod unlock_bonds;
+pub use unlock_bonds::*;
+
+pub mod cast_vote;
+pub use cast_vote::*;
+
+pub mod release_vote;
+pub use release_vote::*;
+
+pub mod execute_proposal;
+pub use execute_proposal::*;
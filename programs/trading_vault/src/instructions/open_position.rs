@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::*, Position, PositionSide, ProtocolConfig, Vault, MAX_VAULT_POSITIONS, PAUSE_TRADING,
+};
+
+#[derive(Accounts)]
+#[instruction(params: OpenPositionParams)]
+pub struct OpenPosition<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = backend_wallet,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub backend_wallet: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"position", vault.key().as_ref(), params.market.as_ref()],
+        bump,
+        payer = backend_wallet,
+        space = Position::LEN
+    )]
+    pub position: Account<'info, Position>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct OpenPositionParams {
+    pub market: Pubkey,
+    pub side: PositionSide,
+    pub size: u64,
+    pub base_decimals: u8,
+    pub entry_price: u64,
+    pub collateral: u64,
+}
+
+// Records a trading position opened by the backend on behalf of the vault
+pub fn open_position(ctx: Context<OpenPosition>, params: OpenPositionParams) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let position = &mut ctx.accounts.position;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    vault.check_not_paused(PAUSE_TRADING)?;
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+    require!(params.size > 0, VaultError::InvalidPositionSize);
+    require!(
+        vault.position_count < MAX_VAULT_POSITIONS,
+        VaultError::TooManyPositions
+    );
+
+    position.vault = vault.key();
+    position.market = params.market;
+    position.side = params.side;
+    position.size = params.size;
+    position.base_decimals = params.base_decimals;
+    position.entry_price = params.entry_price;
+    position.mark_price = params.entry_price;
+    position.collateral = params.collateral;
+    position.opened_at = current_time;
+    position.updated_at = current_time;
+    position.bump = ctx.bumps.position;
+
+    // the collateral was posted out of vault funds already in tvl, so it only moves between components
+    vault.positions_value = vault
+        .positions_value
+        .checked_add(params.collateral)
+        .ok_or(VaultError::MathOverflow)?;
+    vault.position_count += 1;
+
+    Ok(())
+}
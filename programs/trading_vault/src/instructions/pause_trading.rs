@@ -1,21 +1,35 @@
 use anchor_lang::prelude::*;
 
-use crate::Vault;
+use crate::{error::*, Role, Vault, PAUSE_ALL};
 
 #[derive(Accounts)]
 pub struct PauseTrading<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.leader.key().as_ref()],
-        bump,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        constraint = vault.has_any_role(&authority.key(), &[Role::Leader, Role::Guardian]) @ VaultError::Unauthorized,
     )]
     pub vault: Account<'info, Vault>,
-    pub leader: Signer<'info>,
+    // the leader or the guardian
+    pub authority: Signer<'info>,
 }
 
-// Pauses trading in the vault
-pub fn pause_trading(ctx: Context<PauseTrading>) -> Result<()> {
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PauseTradingParams {
+    // PAUSE_* flags to set, e.g. WITHDRAW_ONLY during incidents
+    pub pause_flags: u8,
+}
+
+// Pauses the given operations of the vault
+pub fn pause_trading(ctx: Context<PauseTrading>, params: PauseTradingParams) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
-    vault.is_trading_paused = true;
+
+    require!(
+        params.pause_flags & !PAUSE_ALL == 0,
+        VaultError::InvalidPauseFlags
+    );
+
+    vault.pause_flags |= params.pause_flags;
     Ok(())
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::Vault;
+
+#[derive(Accounts)]
+pub struct ProposeLeader<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub leader: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeLeaderParams {
+    // Pubkey::default() cancels a pending proposal
+    pub new_leader: Pubkey,
+}
+
+// Proposes a new leader, who takes over the vault once they accept
+pub fn propose_leader(ctx: Context<ProposeLeader>, params: ProposeLeaderParams) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.pending_leader = params.new_leader;
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{error::*, merkle, Epoch, EpochClaim, ProtocolConfig, User, Vault, PAUSE_DEPOSITS};
+
+#[derive(Accounts)]
+pub struct QueueDeposit<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump,
+        payer = depositor,
+        space = User::LEN
+    )]
+    pub user: Account<'info, User>,
+    #[account(
+        init_if_needed,
+        seeds = [b"epoch", vault.key().as_ref(), vault.current_epoch.to_le_bytes().as_ref()],
+        bump,
+        payer = depositor,
+        space = Epoch::LEN
+    )]
+    pub epoch: Account<'info, Epoch>,
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"epoch_claim",
+            vault.key().as_ref(),
+            vault.current_epoch.to_le_bytes().as_ref(),
+            depositor.key().as_ref(),
+        ],
+        bump,
+        payer = depositor,
+        space = EpochClaim::LEN
+    )]
+    pub epoch_claim: Account<'info, EpochClaim>,
+    #[account(
+        mut,
+        constraint = depositor_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = depositor_pay_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_pay_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub vault_pay_token_account: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QueueDepositParams {
+    amount: u64,          // in usd
+    proof: Vec<[u8; 32]>, // allowlist proof, only checked for private vaults
+}
+
+// Queues a deposit that is priced at the NAV struck when the current epoch settles
+pub fn queue_deposit(ctx: Context<QueueDeposit>, params: QueueDepositParams) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let user = &mut ctx.accounts.user;
+    let epoch = &mut ctx.accounts.epoch;
+    let epoch_claim = &mut ctx.accounts.epoch_claim;
+
+    require!(vault.uses_epochs(), VaultError::EpochPricingDisabled);
+    vault.check_not_paused(PAUSE_DEPOSITS)?;
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+    if vault.is_private {
+        require!(
+            merkle::verify(
+                &params.proof,
+                &vault.allowlist_root,
+                merkle::leaf(ctx.accounts.depositor.key().as_ref()),
+            ),
+            VaultError::NotAllowlisted
+        );
+    }
+    vault.check_deposit_caps(
+        user.deposit_value
+            .saturating_add(epoch_claim.deposit_amount),
+        params.amount,
+    )?;
+
+    vault.transfer_tokens_from_user(
+        ctx.accounts.depositor_pay_token_account.to_account_info(),
+        ctx.accounts.vault_pay_token_account.to_account_info(),
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        params.amount,
+    )?;
+
+    vault.pending_deposits += params.amount;
+
+    epoch.vault = vault.key();
+    epoch.epoch_id = vault.current_epoch;
+    epoch.bump = ctx.bumps.epoch;
+    epoch.total_deposits += params.amount;
+
+    epoch_claim.vault = vault.key();
+    epoch_claim.depositor = ctx.accounts.depositor.key();
+    epoch_claim.epoch_id = vault.current_epoch;
+    epoch_claim.bump = ctx.bumps.epoch_claim;
+    epoch_claim.deposit_amount += params.amount;
+
+    user.bump = ctx.bumps.user;
+
+    Ok(())
+}
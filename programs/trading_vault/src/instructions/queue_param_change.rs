@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{ParamChange, PendingParamChange, Vault};
+
+#[derive(Accounts)]
+pub struct QueueParamChange<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub leader: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"param_change", vault.key().as_ref()],
+        bump,
+        payer = leader,
+        space = PendingParamChange::LEN
+    )]
+    pub pending_param_change: Account<'info, PendingParamChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QueueParamChangeParams {
+    pub change: ParamChange,
+}
+
+// Queues a change of terms and opens the exit window during which lock periods are waived
+pub fn queue_param_change(
+    ctx: Context<QueueParamChange>,
+    params: QueueParamChangeParams,
+) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+
+    ctx.accounts.pending_param_change.queue(
+        vault_key,
+        &mut ctx.accounts.vault,
+        params.change,
+        false,
+        ctx.bumps.pending_param_change,
+        Clock::get()?.unix_timestamp,
+    )
+}
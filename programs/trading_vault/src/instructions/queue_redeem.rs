@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{error::*, Epoch, EpochClaim, User, Vault, PAUSE_WITHDRAWALS};
+
+#[derive(Accounts)]
+pub struct QueueRedeem<'info> {
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = user.bump,
+    )]
+    pub user: Account<'info, User>,
+    #[account(
+        init_if_needed,
+        seeds = [b"epoch", vault.key().as_ref(), vault.current_epoch.to_le_bytes().as_ref()],
+        bump,
+        payer = depositor,
+        space = Epoch::LEN
+    )]
+    pub epoch: Account<'info, Epoch>,
+    #[account(
+        init_if_needed,
+        seeds = [
+            b"epoch_claim",
+            vault.key().as_ref(),
+            vault.current_epoch.to_le_bytes().as_ref(),
+            depositor.key().as_ref(),
+        ],
+        bump,
+        payer = depositor,
+        space = EpochClaim::LEN
+    )]
+    pub epoch_claim: Account<'info, EpochClaim>,
+    #[account(
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
+    pub mint_account: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = depositor_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    // Escrow holding bonds of pending redemptions
+    #[account(
+        init_if_needed,
+        seeds = [b"bond_escrow", vault.key().as_ref()],
+        bump,
+        payer = depositor,
+        token::mint = mint_account,
+        token::authority = vault_authority,
+    )]
+    pub bond_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct QueueRedeemParams {
+    bond_amount: u64,
+}
+
+// Queues a redemption that is priced at the NAV struck when the current epoch settles
+pub fn queue_redeem(ctx: Context<QueueRedeem>, params: QueueRedeemParams) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let user = &mut ctx.accounts.user;
+    let epoch = &mut ctx.accounts.epoch;
+    let epoch_claim = &mut ctx.accounts.epoch_claim;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(vault.uses_epochs(), VaultError::EpochPricingDisabled);
+    vault.check_not_paused(PAUSE_WITHDRAWALS)?;
+    require!(
+        !vault.is_locked(user.deposit_time, current_time),
+        VaultError::LockPeriodNotOver
+    );
+    require!(
+        params.bond_amount > 0 && params.bond_amount <= user.bond_amount,
+        VaultError::InsufficientFunds
+    );
+
+    vault.transfer_tokens_from_user(
+        ctx.accounts.depositor_token_account.to_account_info(),
+        ctx.accounts.bond_escrow.to_account_info(),
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        params.bond_amount,
+    )?;
+
+    let released_deposit = Vault::released_deposit_value(user, params.bond_amount)?;
+
+    user.bond_amount -= params.bond_amount;
+    user.deposit_value -= released_deposit;
+
+    epoch.vault = vault.key();
+    epoch.epoch_id = vault.current_epoch;
+    epoch.bump = ctx.bumps.epoch;
+    epoch.total_redemptions += params.bond_amount;
+    epoch.total_redeemed_deposit_value += released_deposit;
+
+    epoch_claim.vault = vault.key();
+    epoch_claim.depositor = ctx.accounts.depositor.key();
+    epoch_claim.epoch_id = vault.current_epoch;
+    epoch_claim.bump = ctx.bumps.epoch_claim;
+    epoch_claim.redeem_bond_amount += params.bond_amount;
+
+    Ok(())
+}
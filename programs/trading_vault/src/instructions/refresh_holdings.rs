@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{error::*, oracle::OraclePrice, ProtocolConfig, Vault, PAUSE_NAV_REPORTING};
+
+#[derive(Accounts)]
+pub struct RefreshHoldings<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    // remaining accounts: (custody, oracle) of every registered asset, in registration order
+}
+
+// Values every custody account with its oracle price and folds the total into the vault's NAV
+pub fn refresh_holdings(ctx: Context<RefreshHoldings>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    vault.check_not_paused(PAUSE_NAV_REPORTING)?;
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+
+    let assets = vault.registered_assets();
+
+    require!(
+        ctx.remaining_accounts.len() == assets.len() * 2,
+        VaultError::AssetCountMismatch
+    );
+
+    let mut holdings_value: u64 = 0;
+    for (asset, accounts) in assets.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (custody, oracle) = (&accounts[0], &accounts[1]);
+        require_keys_eq!(custody.key(), asset.custody, VaultError::CustodyMismatch);
+        require_keys_eq!(oracle.key(), asset.oracle, VaultError::OracleMismatch);
+
+        let balance = TokenAccount::try_deserialize(&mut &custody.try_borrow_data()?[..])?.amount;
+        let price = OraclePrice::load(oracle, &ctx.accounts.protocol_config.oracle_program)?;
+        price.validate(
+            current_time,
+            vault.oracle_max_staleness_secs,
+            vault.oracle_max_conf_bps,
+        )?;
+
+        holdings_value = holdings_value
+            .checked_add(price.value_of(balance, asset.decimals, vault.quote_decimals)?)
+            .ok_or(VaultError::MathOverflow)?;
+    }
+
+    vault.apply_holdings_value(vault_key, holdings_value, current_time)
+}
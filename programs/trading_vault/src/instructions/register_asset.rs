@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{error::*, oracle::OraclePrice, ProtocolConfig, Vault, VaultAsset, MAX_VAULT_ASSETS};
+
+#[derive(Accounts)]
+pub struct RegisterAsset<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub leader: Signer<'info>,
+    #[account(constraint = asset_mint.key() != vault.quote_mint @ VaultError::QuoteMintAsAsset)]
+    pub asset_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// CHECK: owner and layout are validated by loading the price
+    pub oracle: AccountInfo<'info>,
+    // Custody account holding the asset on behalf of the vault
+    #[account(
+        init_if_needed,
+        seeds = [b"custody", vault.key().as_ref(), asset_mint.key().as_ref()],
+        bump,
+        payer = leader,
+        token::mint = asset_mint,
+        token::authority = vault_authority,
+    )]
+    pub custody: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Registers an asset held by the vault, or replaces the oracle of an already registered one
+pub fn register_asset(ctx: Context<RegisterAsset>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    OraclePrice::load(
+        &ctx.accounts.oracle,
+        &ctx.accounts.protocol_config.oracle_program,
+    )?;
+
+    let asset = VaultAsset {
+        mint: ctx.accounts.asset_mint.key(),
+        decimals: ctx.accounts.asset_mint.decimals,
+        oracle: ctx.accounts.oracle.key(),
+        custody: ctx.accounts.custody.key(),
+    };
+
+    let count = vault.asset_count as usize;
+    match vault.assets[..count]
+        .iter()
+        .position(|a| a.mint == asset.mint)
+    {
+        Some(i) => vault.assets[i] = asset,
+        None => {
+            require!(count < MAX_VAULT_ASSETS, VaultError::TooManyAssets);
+            vault.assets[count] = asset;
+            vault.asset_count += 1;
+        }
+    }
+
+    Ok(())
+}
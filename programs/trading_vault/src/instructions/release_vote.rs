@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Proposal, Vault, VoteRecord};
+
+#[derive(Accounts)]
+pub struct ReleaseVote<'info> {
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    #[account(
+        seeds = [b"proposal", vault.key().as_ref(), proposal.index.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = vault,
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        mut,
+        seeds = [b"vote", proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+        has_one = voter,
+        close = voter,
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+}
+
+// Returns the vote record's rent once voting on the proposal has ended
+pub fn release_vote(ctx: Context<ReleaseVote>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.proposal.voting_ends_at,
+        VaultError::VotingNotOver
+    );
+
+    Ok(())
+}
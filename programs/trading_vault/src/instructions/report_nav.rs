@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, ProtocolConfig, Vault, PAUSE_NAV_REPORTING};
+
+#[derive(Accounts)]
+pub struct ReportNav<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = backend_wallet,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub backend_wallet: Signer<'info>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReportNavParams {
+    pub position_equity: u64, // in usd
+}
+
+// Records the equity of off-chain trading positions reported by the backend; positions tracked in
+// position accounts are valued separately by sync_positions
+pub fn report_nav(ctx: Context<ReportNav>, params: ReportNavParams) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+
+    vault.check_not_paused(PAUSE_NAV_REPORTING)?;
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+
+    vault.apply_position_equity(
+        vault_key,
+        params.position_equity,
+        Clock::get()?.unix_timestamp,
+    )
+}
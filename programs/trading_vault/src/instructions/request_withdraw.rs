@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{error::*, User, Vault, WithdrawRequest, PAUSE_WITHDRAWALS};
+
+#[derive(Accounts)]
+pub struct RequestWithdraw<'info> {
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = user.bump,
+    )]
+    pub user: Account<'info, User>,
+    #[account(
+        init,
+        seeds = [b"withdraw_request", vault.key().as_ref(), depositor.key().as_ref()],
+        bump,
+        payer = depositor,
+        space = WithdrawRequest::LEN
+    )]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+    #[account(
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
+    pub mint_account: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = depositor_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    // Escrow holding bonds of pending withdraw requests
+    #[account(
+        init_if_needed,
+        seeds = [b"bond_escrow", vault.key().as_ref()],
+        bump,
+        payer = depositor,
+        token::mint = mint_account,
+        token::authority = vault_authority,
+    )]
+    pub bond_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RequestWithdrawParams {
+    bond_amount: u64,
+}
+
+// Locks bonds into escrow until the backend unwinds positions and fulfils the request
+pub fn request_withdraw(
+    ctx: Context<RequestWithdraw>,
+    params: RequestWithdrawParams,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let user = &mut ctx.accounts.user;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!vault.uses_epochs(), VaultError::EpochPricingEnabled);
+    vault.check_not_paused(PAUSE_WITHDRAWALS)?;
+
+    require!(
+        !vault.is_locked(user.deposit_time, current_time),
+        VaultError::LockPeriodNotOver
+    );
+    require!(
+        params.bond_amount > 0 && params.bond_amount <= user.bond_amount,
+        VaultError::InsufficientFunds
+    );
+
+    vault.transfer_tokens_from_user(
+        ctx.accounts.depositor_token_account.to_account_info(),
+        ctx.accounts.bond_escrow.to_account_info(),
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        params.bond_amount,
+    )?;
+
+    let released_deposit = Vault::released_deposit_value(user, params.bond_amount)?;
+
+    user.bond_amount -= params.bond_amount;
+    user.deposit_value -= released_deposit;
+
+    let withdraw_request = &mut ctx.accounts.withdraw_request;
+    withdraw_request.vault = vault.key();
+    withdraw_request.depositor = ctx.accounts.depositor.key();
+    withdraw_request.bond_amount = params.bond_amount;
+    withdraw_request.deposit_value = released_deposit;
+    withdraw_request.requested_at = current_time;
+    withdraw_request.bump = ctx.bumps.withdraw_request;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Role, Vault, PAUSE_TRADING};
+
+#[derive(Accounts)]
+pub struct RevokeBackendWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        constraint = vault.has_any_role(&authority.key(), &[Role::Leader, Role::Guardian]) @ VaultError::Unauthorized,
+    )]
+    pub vault: Account<'info, Vault>,
+    // the leader or the guardian
+    pub authority: Signer<'info>,
+}
+
+// Immediately removes the backend wallet and pauses trading; a new one is set through a param change
+pub fn revoke_backend_wallet(ctx: Context<RevokeBackendWallet>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.backend_wallet = Pubkey::default();
+    vault.pause_flags |= PAUSE_TRADING;
+
+    Ok(())
+}
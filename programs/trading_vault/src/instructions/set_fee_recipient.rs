@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::Vault;
+
+#[derive(Accounts)]
+pub struct SetFeeRecipient<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = fee_manager,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub fee_manager: Signer<'info>,
+    #[account(token::mint = vault.quote_mint)]
+    pub fee_recipient: Account<'info, TokenAccount>,
+}
+
+// Routes entry, exit and accrued fees to a new quote token account
+pub fn set_fee_recipient(ctx: Context<SetFeeRecipient>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.fee_recipient = ctx.accounts.fee_recipient.key();
+    Ok(())
+}
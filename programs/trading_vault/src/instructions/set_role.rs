@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Role, Vault};
+
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub leader: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRoleParams {
+    pub role: Role,
+    // Pubkey::default() leaves the role unassigned
+    pub holder: Pubkey,
+}
+
+// Assigns the guardian or fee manager role; the leader and operator change through their own transfer flows
+pub fn set_role(ctx: Context<SetRole>, params: SetRoleParams) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    match params.role {
+        Role::Guardian => vault.guardian = params.holder,
+        Role::FeeManager => vault.fee_manager = params.holder,
+        Role::Leader | Role::Operator => return err!(VaultError::RoleNotAssignable),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    accounting::{assets_for_shares, fee_amount, nav_per_share, shares_for_assets, Rounding},
+    error::*,
+    Epoch, ProtocolConfig, Vault, PAUSE_DEPOSITS, PAUSE_NAV_REPORTING,
+};
+
+#[derive(Accounts)]
+pub struct SettleEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        seeds = [b"epoch", vault.key().as_ref(), vault.current_epoch.to_le_bytes().as_ref()],
+        bump,
+        payer = payer,
+        space = Epoch::LEN
+    )]
+    pub epoch: Account<'info, Epoch>,
+    #[account(
+        mut,
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
+    )]
+    pub mint_account: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        seeds = [b"bond_escrow", vault.key().as_ref()],
+        bump,
+        payer = payer,
+        token::mint = mint_account,
+        token::authority = vault_authority,
+    )]
+    pub bond_escrow: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+// Strikes the NAV for the ended epoch, settles all of its queued requests and opens the next epoch
+pub fn settle_epoch(ctx: Context<SettleEpoch>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let epoch = &mut ctx.accounts.epoch;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(vault.uses_epochs(), VaultError::EpochPricingDisabled);
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+    require!(
+        current_time
+            >= vault
+                .epoch_started_at
+                .saturating_add(vault.epoch_length_secs),
+        VaultError::EpochNotOver
+    );
+
+    vault.accrue_fees(current_time)?;
+
+    // while deposits or NAV reporting are paused only the redemptions settle, the deposits wait
+    // for the next settlement that can price them
+    let (total_deposits, carried_deposits) =
+        if vault.pause_flags & (PAUSE_DEPOSITS | PAUSE_NAV_REPORTING) == 0 {
+            let total_deposits = epoch
+                .total_deposits
+                .checked_add(vault.carried_deposits)
+                .ok_or(VaultError::MathOverflow)?;
+            (total_deposits, vault.carried_deposits)
+        } else {
+            if epoch.total_deposits > 0 && vault.carried_deposits == 0 {
+                vault.carried_from_epoch = vault.current_epoch;
+            }
+            vault.carried_deposits += epoch.total_deposits;
+            epoch.deposits_carried = true;
+            (0, 0)
+        };
+
+    // every request of the epoch is priced at the same NAV
+    let total_assets = vault.total_assets();
+    let bond_supply = vault.bond_supply;
+
+    let payout = assets_for_shares(
+        epoch.total_redemptions,
+        vault.redeemable_assets(),
+        bond_supply,
+        Rounding::Down,
+    )?;
+    let exit_fee = fee_amount(payout, vault.exit_fee_bps)?;
+    let entry_fee = fee_amount(total_deposits, vault.entry_fee_bps)?;
+    let net_deposits = total_deposits - entry_fee;
+    let bonds_minted = shares_for_assets(net_deposits, total_assets, bond_supply, Rounding::Down)?;
+
+    if epoch.total_redemptions > 0 {
+        vault.burn_bonds(
+            vault_key,
+            ctx.accounts.mint_account.to_account_info(),
+            ctx.accounts.bond_escrow.to_account_info(),
+            ctx.accounts.vault_authority.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            epoch.total_redemptions,
+        )?;
+    }
+
+    // entry and exit fees stay in the vault and are paid out through claim_fees
+    vault.tvl = (vault.tvl - (payout - exit_fee))
+        .checked_add(total_deposits)
+        .ok_or(VaultError::MathOverflow)?;
+    vault.accrued_fees += entry_fee + exit_fee;
+    vault.bond_supply = bond_supply - epoch.total_redemptions + bonds_minted;
+    vault.deposit_value = vault.deposit_value - epoch.total_redeemed_deposit_value + net_deposits;
+    vault.pending_deposits -= total_deposits;
+    if carried_deposits > 0 {
+        epoch.carried_from = vault.carried_from_epoch;
+        epoch.carried_deposits = carried_deposits;
+        vault.carried_deposits = 0;
+    }
+
+    epoch.vault = vault_key;
+    epoch.epoch_id = vault.current_epoch;
+    epoch.bump = ctx.bumps.epoch;
+    epoch.is_settled = true;
+    epoch.settled_at = current_time;
+    epoch.nav = nav_per_share(total_assets, bond_supply)?;
+    epoch.net_deposits = net_deposits;
+    epoch.bonds_minted = bonds_minted;
+    epoch.net_payout = payout - exit_fee;
+
+    vault.current_epoch += 1;
+    vault.epoch_started_at = current_time;
+
+    vault.refresh_bond_price()?;
+
+    Ok(())
+}
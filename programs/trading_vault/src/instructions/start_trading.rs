@@ -1,21 +1,48 @@
 use anchor_lang::prelude::*;
 
-use crate::Vault;
+use crate::{error::*, Vault, PAUSE_ALL, PAUSE_TRADING, PAUSE_WITHDRAWALS};
 
 #[derive(Accounts)]
 pub struct StartTrading<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.leader.key().as_ref()],
-        bump,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
     )]
     pub vault: Account<'info, Vault>,
     pub leader: Signer<'info>,
 }
 
-// Pauses trading in the vault
-pub fn start_trading(ctx: Context<StartTrading>) -> Result<()> {
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StartTradingParams {
+    // PAUSE_* flags to clear
+    pub pause_flags: u8,
+}
+
+// Resumes the given operations of the vault
+pub fn start_trading(ctx: Context<StartTrading>, params: StartTradingParams) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
-    vault.is_trading_paused = false;
+
+    // a terminated vault can only let its depositors out again
+    require!(
+        !vault.is_terminated || params.pause_flags == PAUSE_WITHDRAWALS,
+        VaultError::VaultTerminated
+    );
+    require!(
+        params.pause_flags & !PAUSE_ALL == 0,
+        VaultError::InvalidPauseFlags
+    );
+
+    // resuming trading after a drawdown waits out the cooldown and restarts the peak from here
+    if params.pause_flags & PAUSE_TRADING != 0 {
+        vault.reset_drawdown(Clock::get()?.unix_timestamp)?;
+    }
+
+    if params.pause_flags & vault.pause_flags & PAUSE_WITHDRAWALS != 0 {
+        vault.withdrawals_resumed_at = Clock::get()?.unix_timestamp;
+    }
+
+    vault.pause_flags &= !params.pause_flags;
     Ok(())
 }
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Position, ProtocolConfig, Vault, PAUSE_NAV_REPORTING};
+
+#[derive(Accounts)]
+pub struct SyncPositions<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    // remaining accounts: every open position of the vault, sorted by address
+}
+
+// Sums the equity of the vault's position accounts and folds it into the NAV
+pub fn sync_positions(ctx: Context<SyncPositions>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let vault = &mut ctx.accounts.vault;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    vault.check_not_paused(PAUSE_NAV_REPORTING)?;
+    require!(
+        !ctx.accounts.protocol_config.is_paused,
+        VaultError::ProtocolPaused
+    );
+    require!(
+        ctx.remaining_accounts.len() == vault.position_count as usize,
+        VaultError::PositionCountMismatch
+    );
+
+    let mut positions_value: u64 = 0;
+    let mut previous_key: Option<Pubkey> = None;
+    for account in ctx.remaining_accounts {
+        // strictly increasing addresses rule out counting a position twice
+        if let Some(previous_key) = previous_key {
+            require!(
+                previous_key < account.key(),
+                VaultError::PositionCountMismatch
+            );
+        }
+        previous_key = Some(account.key());
+
+        require_keys_eq!(*account.owner, crate::ID, VaultError::PositionMismatch);
+        let position = Position::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        require_keys_eq!(position.vault, vault_key, VaultError::PositionMismatch);
+
+        positions_value = positions_value
+            .checked_add(position.equity()?)
+            .ok_or(VaultError::MathOverflow)?;
+    }
+
+    vault.apply_positions_value(vault_key, positions_value, current_time)
+}
@@ -1,48 +1,41 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount};
 
-use crate::Vault;
+use crate::{ParamChange, PendingParamChange, Vault};
 
 #[derive(Accounts)]
 pub struct TerminateVault<'info> {
-    pub leader: Signer<'info>,
-    /// CHECK:
     #[account(mut)]
-    pub backend_wallet: AccountInfo<'info>,
+    pub leader: Signer<'info>,
 
     #[account(
-        seeds = [b"vault", leader.key().as_ref()],
-        bump,
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
     )]
     pub vault: Account<'info, Vault>,
-    /// CHECK:
     #[account(
-        seeds = [b"vault_authority"],
+        init,
+        seeds = [b"param_change", vault.key().as_ref()],
         bump,
-        )]
-    pub vault_authority: AccountInfo<'info>,
-    #[account(mut)]
-    pub vault_pay_token_account: Account<'info, TokenAccount>,
+        payer = leader,
+        space = PendingParamChange::LEN
+    )]
+    pub pending_param_change: Account<'info, PendingParamChange>,
 
-    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-// Terminates the vault and distributes funds to all depositors
+// Queues the vault's termination behind the param change delay; funds stay in the vault for depositors to withdraw
 pub fn terminate_vault(ctx: Context<TerminateVault>) -> Result<()> {
-    let vault = &mut ctx.accounts.vault;
-
-    vault.transfer_tokens(
-        ctx.accounts.vault_pay_token_account.to_account_info(),
-        ctx.accounts.backend_wallet.to_account_info(),
-        ctx.accounts.vault_authority.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.vault_pay_token_account.get_lamports(),
-    )?;
-
-    vault.tvl = 0;
-    vault.deposit_value = 0;
-    vault.bond_price = 0;
-    vault.bond_supply = 0;
-
-    Ok(())
+    let vault_key = ctx.accounts.vault.key();
+
+    ctx.accounts.pending_param_change.queue(
+        vault_key,
+        &mut ctx.accounts.vault,
+        ParamChange::Terminate,
+        false,
+        ctx.bumps.pending_param_change,
+        Clock::get()?.unix_timestamp,
+    )
 }
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{error::*, Vault, VoteLock};
+
+#[derive(Accounts)]
+pub struct UnlockBonds<'info> {
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK:
+    #[account(
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vote_lock", vault.key().as_ref(), owner.key().as_ref()],
+        bump = vote_lock.bump,
+        has_one = owner,
+    )]
+    pub vote_lock: Account<'info, VoteLock>,
+    #[account(
+        mut,
+        constraint = owner_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = owner_token_account.owner == owner.key() @ VaultError::InvalidTokenOwner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        seeds = [b"vote_lock_escrow", vault.key().as_ref()],
+        bump,
+    )]
+    pub vote_lock_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UnlockBondsParams {
+    pub amount: u64,
+}
+
+// Returns locked bonds to their holder. Votes already cast keep their weight: the bonds can't
+// vote again on those proposals, since any lock they move into is newer than the proposal.
+pub fn unlock_bonds(ctx: Context<UnlockBonds>, params: UnlockBondsParams) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+
+    require!(
+        params.amount > 0 && params.amount <= ctx.accounts.vote_lock.amount,
+        VaultError::InsufficientFunds
+    );
+
+    ctx.accounts.vault.transfer_tokens(
+        vault_key,
+        ctx.accounts.vote_lock_escrow.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.vault_authority.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        params.amount,
+    )?;
+
+    ctx.accounts.vote_lock.amount -= params.amount;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::Vault;
+
+#[derive(Accounts)]
+pub struct UpdateAllowlist<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub leader: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateAllowlistParams {
+    pub is_private: bool,
+    pub allowlist_root: [u8; 32],
+}
+
+// Makes the vault private or public and rotates the allowlist Merkle root
+pub fn update_allowlist(
+    ctx: Context<UpdateAllowlist>,
+    params: UpdateAllowlistParams,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.is_private = params.is_private;
+    vault.allowlist_root = params.allowlist_root;
+    Ok(())
+}
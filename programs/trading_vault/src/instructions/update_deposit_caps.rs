@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::Vault;
+
+#[derive(Accounts)]
+pub struct UpdateDepositCaps<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub leader: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateDepositCapsParams {
+    pub max_tvl: u64,              // 0 for uncapped
+    pub max_deposit_per_user: u64, // 0 for uncapped
+}
+
+// Adjusts the vault TVL cap and the per-depositor cap; existing deposits are not affected
+pub fn update_deposit_caps(
+    ctx: Context<UpdateDepositCaps>,
+    params: UpdateDepositCapsParams,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.max_tvl = params.max_tvl;
+    vault.max_deposit_per_user = params.max_deposit_per_user;
+    Ok(())
+}
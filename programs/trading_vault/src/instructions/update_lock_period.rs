@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Vault};
+
+#[derive(Accounts)]
+pub struct UpdateLockPeriod<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = leader,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub leader: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateLockPeriodParams {
+    pub lock_period_secs: i64,
+}
+
+// Shortens the lock period immediately; extending it goes through a timelocked param change
+pub fn update_lock_period(
+    ctx: Context<UpdateLockPeriod>,
+    params: UpdateLockPeriodParams,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    require!(params.lock_period_secs >= 0, VaultError::InvalidLockPeriod);
+    require!(
+        params.lock_period_secs <= vault.lock_period_secs,
+        VaultError::LockPeriodIncrease
+    );
+
+    vault.lock_period_secs = params.lock_period_secs;
+    Ok(())
+}
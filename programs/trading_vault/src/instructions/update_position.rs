@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::*, Position, Vault, PAUSE_TRADING};
+
+#[derive(Accounts)]
+pub struct UpdatePosition<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = backend_wallet,
+    )]
+    pub vault: Account<'info, Vault>,
+    pub backend_wallet: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"position", vault.key().as_ref(), position.market.as_ref()],
+        bump = position.bump,
+        has_one = vault @ VaultError::PositionMismatch,
+    )]
+    pub position: Account<'info, Position>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdatePositionParams {
+    pub size: u64,
+    pub entry_price: u64,
+    pub mark_price: u64,
+    pub collateral: u64,
+}
+
+// Records a resize, margin change or new mark price of an open position
+pub fn update_position(ctx: Context<UpdatePosition>, params: UpdatePositionParams) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let position = &mut ctx.accounts.position;
+
+    require!(params.size > 0, VaultError::InvalidPositionSize);
+
+    // growing the exposure counts as trading, marking it to market does not
+    if params.size > position.size {
+        vault.check_not_paused(PAUSE_TRADING)?;
+    }
+
+    // margin moves between idle cash and the position; PnL reaches the NAV through sync_positions
+    vault.positions_value = vault
+        .positions_value
+        .saturating_sub(position.collateral)
+        .checked_add(params.collateral)
+        .ok_or(VaultError::MathOverflow)?;
+
+    position.size = params.size;
+    position.entry_price = params.entry_price;
+    position.mark_price = params.mark_price;
+    position.collateral = params.collateral;
+    position.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
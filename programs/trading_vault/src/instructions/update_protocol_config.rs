@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{ProtocolConfig, ProtocolConfigParams};
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+        has_one = admin,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+// Updates the treasury, protocol fee share, allowed quote mints, allowed swap programs, oracle program and global pause
+pub fn update_protocol_config(
+    ctx: Context<UpdateProtocolConfig>,
+    params: ProtocolConfigParams,
+) -> Result<()> {
+    ctx.accounts.protocol_config.apply(params)
+}
@@ -1,46 +1,73 @@
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{burn, Burn, Mint, Token, TokenAccount};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
-use crate::{error::*, User, Vault, TOKEN_DECIMALS};
+use crate::{
+    accounting::{fee_amount, shares_for_assets, Rounding},
+    error::*,
+    ExitFeeAccounts, ProtocolConfig, User, Vault, PAUSE_WITHDRAWALS,
+};
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(
         mut,
-        seeds = [b"vault", vault.leader.key().as_ref()],
-        bump,
+        seeds = [b"vault", vault.creator.as_ref(), vault.index.to_le_bytes().as_ref()],
+        bump = vault.bump,
+        has_one = vault_pay_token_account @ VaultError::InvalidVaultTokenAccount,
     )]
     pub vault: Account<'info, Vault>,
     /// CHECK:
     #[account(
-        seeds = [b"vault_authority"],
-        bump,
+        seeds = [b"vault_authority", vault.key().as_ref()],
+        bump = vault.vault_authority_bump,
         )]
     pub vault_authority: AccountInfo<'info>,
     #[account(mut)]
     pub depositor: Signer<'info>,
     #[account(
-        init_if_needed,
-        seeds = [b"user", depositor.key().as_ref()],
-        bump,
-        payer = depositor,
-        space = User::LEN
+        mut,
+        seeds = [b"user", vault.key().as_ref(), depositor.key().as_ref()],
+        bump = user.bump,
     )]
     pub user: Account<'info, User>,
     // Mint account address is a PDA
     #[account(
         mut,
-        seeds = [b"mint"],
-        bump
+        seeds = [b"mint", vault.key().as_ref()],
+        bump = vault.mint_bump,
     )]
     pub mint_account: Account<'info, Mint>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = depositor_pay_token_account.mint == vault.quote_mint @ VaultError::InvalidQuoteMint,
+        constraint = depositor_pay_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
     pub depositor_pay_token_account: Account<'info, TokenAccount>,
     #[account(mut)]
     pub vault_pay_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == vault.bond_mint @ VaultError::InvalidBondMint,
+        constraint = depositor_token_account.owner == depositor.key() @ VaultError::InvalidTokenOwner,
+    )]
     pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        address = vault.fee_recipient @ VaultError::InvalidFeeRecipient,
+    )]
+    pub fee_recipient: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"protocol_config"],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        mut,
+        token::mint = vault.quote_mint,
+        token::authority = protocol_config.treasury,
+    )]
+    pub protocol_treasury: Account<'info, TokenAccount>,
     
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -53,74 +80,81 @@ pub struct WithdrawParams {
 
 // Allows users to withdraw their funds after the lock period
 pub fn withdraw(ctx: Context<Withdraw>, params: WithdrawParams) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
     let vault = &mut ctx.accounts.vault;
     let user = &mut ctx.accounts.user;
     let current_time = Clock::get()?.unix_timestamp;
 
+    require!(!vault.uses_epochs(), VaultError::EpochPricingEnabled);
+    vault.check_not_paused(PAUSE_WITHDRAWALS)?;
+
     require!(
-        current_time < user.deposit_time + 5 * 86400,
+        !vault.is_locked(user.deposit_time, current_time),
         VaultError::LockPeriodNotOver
     );
 
+    vault.accrue_fees(current_time)?;
+
+    // bonds burned for the requested amount are rounded up in favour of the vault
+    let bond_value = shares_for_assets(
+        params.amount,
+        vault.redeemable_assets(),
+        vault.bond_supply,
+        Rounding::Up,
+    )?;
+
     require!(
-        params.amount > user.deposit_value,
+        bond_value <= user.bond_amount,
         VaultError::InsufficientFunds
     );
 
+    let released_deposit = Vault::released_deposit_value(user, bond_value)?;
+
+    let exit_fee = fee_amount(params.amount, vault.exit_fee_bps)?;
+
     // transfer usdc from vault to user
     vault.transfer_tokens(
+        vault_key,
         ctx.accounts.vault_pay_token_account.to_account_info(),
         ctx.accounts.depositor_pay_token_account.to_account_info(),
         ctx.accounts.vault_authority.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
-        params.amount
+        params.amount - exit_fee
     )?;
 
-    let mut bond_value = (params.amount / vault.bond_price) as u64 *10u64.pow(TOKEN_DECIMALS as u32);
-
-    if ctx.accounts.depositor.key() == vault.leader {
-        //  transfer performance fee
-        let performance_fee = ( vault.tvl - vault.deposit_value ) / 10;
-        vault.transfer_tokens(
-            ctx.accounts.vault_pay_token_account.to_account_info(),
-            ctx.accounts.depositor_pay_token_account.to_account_info(),
-            ctx.accounts.vault_authority.to_account_info(),
-            ctx.accounts.token_program.to_account_info(),
-            performance_fee
-        )?;
-
-        vault.tvl -= performance_fee;
-        bond_value += (performance_fee / vault.bond_price) as u64 *10u64.pow(TOKEN_DECIMALS as u32);
-
-    }
+    vault.pay_exit_fee(
+        vault_key,
+        ExitFeeAccounts {
+            vault_pay_token_account: ctx.accounts.vault_pay_token_account.to_account_info(),
+            fee_recipient: ctx.accounts.fee_recipient.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            vault_authority: ctx.accounts.vault_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+        exit_fee,
+        ctx.accounts.protocol_config.protocol_fee_bps,
+    )?;
 
-    
     user.bond_amount -= bond_value;
-    user.deposit_value -= params.amount;
+    user.deposit_value -= released_deposit;
     
     // Update vault info
     vault.tvl -= params.amount;
-    vault.deposit_value -= params.amount;
+    vault.deposit_value -= released_deposit;
     vault.bond_supply -=  bond_value;
 
     // burn user's withdrawal bond amount
-    // PDA signer seeds
-    let signer_seeds: &[&[&[u8]]] = &[&[b"mint", &[ctx.bumps.mint_account]]];
-    
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info().clone(),
-        Burn {
-            mint: ctx.accounts.mint_account.to_account_info(),
-            from: ctx.accounts.depositor_token_account.to_account_info(),
-            authority: ctx.accounts.mint_account.to_account_info(),
-        },
-        signer_seeds,
-    );
-    burn(cpi_ctx, bond_value)?;
+    vault.burn_bonds(
+        vault_key,
+        ctx.accounts.mint_account.to_account_info(),
+        ctx.accounts.depositor_token_account.to_account_info(),
+        ctx.accounts.depositor.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        bond_value,
+    )?;
 
     // recalculate bond price
-    let profit = vault.tvl - vault.deposit_value;
-    vault.bond_price = (vault.deposit_value + profit * 80 / 100) / vault.bond_supply;
+    vault.refresh_bond_price()?;
 
     Ok(())
 }
@@ -1,6 +1,10 @@
+pub mod accounting;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod merkle;
+pub mod oracle;
 pub mod state;
 
 use anchor_lang::prelude::*;
@@ -15,8 +19,25 @@ declare_id!("4aeW1288H4t5oSmUhmrxmVfvuhFgYrtPSj6BGwCC4djv");
 pub mod trading_vault {
     use super::*;
 
-    pub fn vault_initialize(ctx: Context<InitializeVault>) -> Result<()> {
-        initialize_vault(ctx)
+    pub fn protocol_initialize(
+        ctx: Context<InitializeProtocol>,
+        params: ProtocolConfigParams,
+    ) -> Result<()> {
+        initialize_protocol(ctx, params)
+    }
+
+    pub fn protocol_update_config(
+        ctx: Context<UpdateProtocolConfig>,
+        params: ProtocolConfigParams,
+    ) -> Result<()> {
+        update_protocol_config(ctx, params)
+    }
+
+    pub fn vault_initialize(
+        ctx: Context<InitializeVault>,
+        params: InitializeVaultParams,
+    ) -> Result<()> {
+        initialize_vault(ctx, params)
     }
 
     pub fn vault_init_deposit(ctx: Context<InitDeposit>, params: InitDepositParams) -> Result<()> {
@@ -31,19 +52,182 @@ pub mod trading_vault {
         withdraw(ctx, params)
     }
 
-    pub fn vault_pause_trading(ctx: Context<PauseTrading>) -> Result<()> {
-        pause_trading(ctx)
+    pub fn vault_pause_trading(ctx: Context<PauseTrading>, params: PauseTradingParams) -> Result<()> {
+        pause_trading(ctx, params)
     }
 
-    pub fn vault_start_trading(ctx: Context<StartTrading>) -> Result<()> {
-        start_trading(ctx)
+    pub fn vault_start_trading(ctx: Context<StartTrading>, params: StartTradingParams) -> Result<()> {
+        start_trading(ctx, params)
     }
 
-    pub fn vault_close_position(ctx: Context<ClosePosition>) -> Result<()> {
-        close_position(ctx)
+    pub fn vault_force_redeem(ctx: Context<ForceRedeem>) -> Result<()> {
+        force_redeem(ctx)
     }
 
     pub fn vault_terminate_vault(ctx: Context<TerminateVault>) -> Result<()> {
         terminate_vault(ctx)
     }
+
+    pub fn vault_update_lock_period(
+        ctx: Context<UpdateLockPeriod>,
+        params: UpdateLockPeriodParams,
+    ) -> Result<()> {
+        update_lock_period(ctx, params)
+    }
+
+    pub fn vault_claim_fees(ctx: Context<ClaimFees>) -> Result<()> {
+        claim_fees(ctx)
+    }
+
+    pub fn vault_accrue_fees(ctx: Context<AccrueFees>) -> Result<()> {
+        accrue_fees(ctx)
+    }
+
+    pub fn vault_set_fee_recipient(ctx: Context<SetFeeRecipient>) -> Result<()> {
+        set_fee_recipient(ctx)
+    }
+
+    pub fn vault_report_nav(ctx: Context<ReportNav>, params: ReportNavParams) -> Result<()> {
+        report_nav(ctx, params)
+    }
+
+    pub fn vault_register_asset(ctx: Context<RegisterAsset>) -> Result<()> {
+        register_asset(ctx)
+    }
+
+    pub fn vault_refresh_holdings(ctx: Context<RefreshHoldings>) -> Result<()> {
+        refresh_holdings(ctx)
+    }
+
+    pub fn vault_update_deposit_caps(
+        ctx: Context<UpdateDepositCaps>,
+        params: UpdateDepositCapsParams,
+    ) -> Result<()> {
+        update_deposit_caps(ctx, params)
+    }
+
+    pub fn vault_update_allowlist(
+        ctx: Context<UpdateAllowlist>,
+        params: UpdateAllowlistParams,
+    ) -> Result<()> {
+        update_allowlist(ctx, params)
+    }
+
+    pub fn vault_request_withdraw(
+        ctx: Context<RequestWithdraw>,
+        params: RequestWithdrawParams,
+    ) -> Result<()> {
+        request_withdraw(ctx, params)
+    }
+
+    pub fn vault_fulfill_withdraw(ctx: Context<FulfillWithdraw>) -> Result<()> {
+        fulfill_withdraw(ctx)
+    }
+
+    pub fn vault_queue_deposit(ctx: Context<QueueDeposit>, params: QueueDepositParams) -> Result<()> {
+        queue_deposit(ctx, params)
+    }
+
+    pub fn vault_queue_redeem(ctx: Context<QueueRedeem>, params: QueueRedeemParams) -> Result<()> {
+        queue_redeem(ctx, params)
+    }
+
+    pub fn vault_settle_epoch(ctx: Context<SettleEpoch>) -> Result<()> {
+        settle_epoch(ctx)
+    }
+
+    pub fn vault_claim_epoch(ctx: Context<ClaimEpoch>, params: ClaimEpochParams) -> Result<()> {
+        claim_epoch(ctx, params)
+    }
+
+    pub fn vault_execute_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteSwap<'info>>,
+        params: ExecuteSwapParams,
+    ) -> Result<()> {
+        execute_swap(ctx, params)
+    }
+
+    pub fn vault_open_position(ctx: Context<OpenPosition>, params: OpenPositionParams) -> Result<()> {
+        open_position(ctx, params)
+    }
+
+    pub fn vault_update_position(
+        ctx: Context<UpdatePosition>,
+        params: UpdatePositionParams,
+    ) -> Result<()> {
+        update_position(ctx, params)
+    }
+
+    pub fn vault_close_position(
+        ctx: Context<ClosePosition>,
+        params: ClosePositionParams,
+    ) -> Result<()> {
+        close_position(ctx, params)
+    }
+
+    pub fn vault_sync_positions(ctx: Context<SyncPositions>) -> Result<()> {
+        sync_positions(ctx)
+    }
+
+    pub fn vault_set_role(ctx: Context<SetRole>, params: SetRoleParams) -> Result<()> {
+        set_role(ctx, params)
+    }
+
+    pub fn vault_propose_leader(ctx: Context<ProposeLeader>, params: ProposeLeaderParams) -> Result<()> {
+        propose_leader(ctx, params)
+    }
+
+    pub fn vault_accept_leader(ctx: Context<AcceptLeader>) -> Result<()> {
+        accept_leader(ctx)
+    }
+
+    pub fn vault_revoke_backend_wallet(ctx: Context<RevokeBackendWallet>) -> Result<()> {
+        revoke_backend_wallet(ctx)
+    }
+
+    pub fn vault_queue_param_change(
+        ctx: Context<QueueParamChange>,
+        params: QueueParamChangeParams,
+    ) -> Result<()> {
+        queue_param_change(ctx, params)
+    }
+
+    pub fn vault_execute_param_change(ctx: Context<ExecuteParamChange>) -> Result<()> {
+        execute_param_change(ctx)
+    }
+
+    pub fn vault_cancel_param_change(ctx: Context<CancelParamChange>) -> Result<()> {
+        cancel_param_change(ctx)
+    }
+
+    pub fn vault_create_proposal(
+        ctx: Context<CreateProposal>,
+        params: CreateProposalParams,
+    ) -> Result<()> {
+        create_proposal(ctx, params)
+    }
+
+    pub fn vault_cast_vote(ctx: Context<CastVote>, params: CastVoteParams) -> Result<()> {
+        cast_vote(ctx, params)
+    }
+
+    pub fn vault_execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        execute_proposal(ctx)
+    }
+
+    pub fn vault_cancel_withdraw(ctx: Context<CancelWithdraw>) -> Result<()> {
+        cancel_withdraw(ctx)
+    }
+
+    pub fn vault_release_vote(ctx: Context<ReleaseVote>) -> Result<()> {
+        release_vote(ctx)
+    }
+
+    pub fn vault_lock_bonds(ctx: Context<LockBonds>, params: LockBondsParams) -> Result<()> {
+        lock_bonds(ctx, params)
+    }
+
+    pub fn vault_unlock_bonds(ctx: Context<UnlockBonds>, params: UnlockBondsParams) -> Result<()> {
+        unlock_bonds(ctx, params)
+    }
 }
@@ -0,0 +1,62 @@
+use anchor_lang::solana_program::keccak::hashv;
+
+// Leaf committed to by the allowlist root for `key`
+pub fn leaf(key: &[u8]) -> [u8; 32] {
+    hashv(&[key]).to_bytes()
+}
+
+// Verifies a proof built with sorted-pair keccak256 hashing
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[&a, &b]).to_bytes()
+        } else {
+            hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    #[test]
+    fn verifies_every_leaf_of_a_four_leaf_tree() {
+        let leaves: Vec<[u8; 32]> = (0u8..4).map(|i| leaf(&[i; 32])).collect();
+        let left = parent(leaves[0], leaves[1]);
+        let right = parent(leaves[2], leaves[3]);
+        let root = parent(left, right);
+
+        assert!(verify(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify(&[leaves[0], right], &root, leaves[1]));
+        assert!(verify(&[leaves[3], left], &root, leaves[2]));
+        assert!(verify(&[leaves[2], left], &root, leaves[3]));
+    }
+
+    #[test]
+    fn single_leaf_tree_needs_an_empty_proof() {
+        let only = leaf(&[7; 32]);
+        assert!(verify(&[], &only, only));
+    }
+
+    #[test]
+    fn rejects_foreign_leaves_and_tampered_proofs() {
+        let a = leaf(&[1; 32]);
+        let b = leaf(&[2; 32]);
+        let root = parent(a, b);
+
+        assert!(!verify(&[b], &root, leaf(&[3; 32])));
+        assert!(!verify(&[leaf(&[3; 32])], &root, a));
+        assert!(!verify(&[], &root, a));
+        assert!(!verify(&[b, b], &root, a));
+    }
+}
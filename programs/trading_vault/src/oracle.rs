@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    accounting::{mul_div, Rounding},
+    error::VaultError,
+    BPS_DENOMINATOR,
+};
+
+// Pyth v2 price account layout
+const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+const PYTH_ACCOUNT_TYPE_PRICE: u32 = 3;
+const PYTH_STATUS_TRADING: u32 = 1;
+
+const MAGIC_OFFSET: usize = 0;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+const PRICE_ACCOUNT_MIN_LEN: usize = 240;
+
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(&data[offset..offset + N]);
+    bytes
+}
+
+impl OraclePrice {
+    // Reads the aggregate price from a Pyth-compatible price account owned by `oracle_program`
+    pub fn load(account: &AccountInfo, oracle_program: &Pubkey) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            *oracle_program,
+            VaultError::InvalidOracleAccount
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= PRICE_ACCOUNT_MIN_LEN,
+            VaultError::InvalidOracleAccount
+        );
+        require!(
+            u32::from_le_bytes(read_bytes(&data, MAGIC_OFFSET)) == PYTH_MAGIC
+                && u32::from_le_bytes(read_bytes(&data, ACCOUNT_TYPE_OFFSET))
+                    == PYTH_ACCOUNT_TYPE_PRICE,
+            VaultError::InvalidOracleAccount
+        );
+        require!(
+            u32::from_le_bytes(read_bytes(&data, AGG_STATUS_OFFSET)) == PYTH_STATUS_TRADING,
+            VaultError::InvalidOraclePrice
+        );
+
+        Ok(Self {
+            price: i64::from_le_bytes(read_bytes(&data, AGG_PRICE_OFFSET)),
+            conf: u64::from_le_bytes(read_bytes(&data, AGG_CONF_OFFSET)),
+            expo: i32::from_le_bytes(read_bytes(&data, EXPO_OFFSET)),
+            publish_time: i64::from_le_bytes(read_bytes(&data, TIMESTAMP_OFFSET)),
+        })
+    }
+
+    // Rejects non-positive, stale or wide-confidence prices
+    pub fn validate(
+        &self,
+        current_time: i64,
+        max_staleness_secs: i64,
+        max_conf_bps: u16,
+    ) -> Result<()> {
+        require!(self.price > 0, VaultError::InvalidOraclePrice);
+        require!(
+            current_time.saturating_sub(self.publish_time) <= max_staleness_secs,
+            VaultError::StaleOraclePrice
+        );
+
+        let max_conf = mul_div(
+            self.price as u128,
+            max_conf_bps as u128,
+            BPS_DENOMINATOR as u128,
+            Rounding::Down,
+        )?;
+        require!(
+            self.conf as u128 <= max_conf,
+            VaultError::OracleConfidenceTooWide
+        );
+        Ok(())
+    }
+
+    // Value of `amount` base units of an asset with `asset_decimals`, in quote base units, rounded down
+    pub fn value_of(&self, amount: u64, asset_decimals: u8, quote_decimals: u8) -> Result<u64> {
+        let scale = self.expo + quote_decimals as i32 - asset_decimals as i32;
+        let pow = 10u128
+            .checked_pow(scale.unsigned_abs())
+            .ok_or(VaultError::MathOverflow)?;
+
+        let value = if scale >= 0 {
+            (amount as u128)
+                .checked_mul(self.price as u128)
+                .and_then(|v| v.checked_mul(pow))
+                .ok_or(VaultError::MathOverflow)?
+        } else {
+            mul_div(amount as u128, self.price as u128, pow, Rounding::Down)?
+        };
+        u64::try_from(value).map_err(|_| VaultError::MathOverflow.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn price_account_data(price: i64, conf: u64, expo: i32, publish_time: i64) -> Vec<u8> {
+        let mut data = vec![0u8; PRICE_ACCOUNT_MIN_LEN];
+        data[MAGIC_OFFSET..MAGIC_OFFSET + 4].copy_from_slice(&PYTH_MAGIC.to_le_bytes());
+        data[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4]
+            .copy_from_slice(&PYTH_ACCOUNT_TYPE_PRICE.to_le_bytes());
+        data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+        data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&publish_time.to_le_bytes());
+        data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&price.to_le_bytes());
+        data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&conf.to_le_bytes());
+        data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4]
+            .copy_from_slice(&PYTH_STATUS_TRADING.to_le_bytes());
+        data
+    }
+
+    fn load(data: &mut [u8], owner: &Pubkey, oracle_program: &Pubkey) -> Result<OraclePrice> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let account = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+        OraclePrice::load(&account, oracle_program)
+    }
+
+    fn price(price: i64, conf: u64, expo: i32, publish_time: i64) -> OraclePrice {
+        OraclePrice {
+            price,
+            conf,
+            expo,
+            publish_time,
+        }
+    }
+
+    #[test]
+    fn load_reads_the_aggregate_price() {
+        let oracle_program = Pubkey::new_unique();
+        let mut data = price_account_data(6_512_345, 1_200, -5, NOW);
+
+        let loaded = load(&mut data, &oracle_program, &oracle_program).unwrap();
+        assert_eq!(loaded.price, 6_512_345);
+        assert_eq!(loaded.conf, 1_200);
+        assert_eq!(loaded.expo, -5);
+        assert_eq!(loaded.publish_time, NOW);
+    }
+
+    #[test]
+    fn load_rejects_accounts_not_owned_by_the_oracle_program() {
+        let mut data = price_account_data(100, 1, 0, NOW);
+        assert!(load(&mut data, &Pubkey::new_unique(), &Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn load_rejects_malformed_accounts() {
+        let oracle_program = Pubkey::new_unique();
+
+        let mut short = price_account_data(100, 1, 0, NOW);
+        short.truncate(PRICE_ACCOUNT_MIN_LEN - 1);
+        assert!(load(&mut short, &oracle_program, &oracle_program).is_err());
+
+        let mut bad_magic = price_account_data(100, 1, 0, NOW);
+        bad_magic[MAGIC_OFFSET] ^= 0xff;
+        assert!(load(&mut bad_magic, &oracle_program, &oracle_program).is_err());
+
+        let mut product = price_account_data(100, 1, 0, NOW);
+        product[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4].copy_from_slice(&2u32.to_le_bytes());
+        assert!(load(&mut product, &oracle_program, &oracle_program).is_err());
+
+        let mut halted = price_account_data(100, 1, 0, NOW);
+        halted[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&0u32.to_le_bytes());
+        assert!(load(&mut halted, &oracle_program, &oracle_program).is_err());
+    }
+
+    #[test]
+    fn validate_checks_sign_staleness_and_confidence() {
+        assert!(price(10_000, 100, -2, NOW - 30)
+            .validate(NOW, 60, 100)
+            .is_ok());
+        assert!(price(0, 0, -2, NOW).validate(NOW, 60, 100).is_err());
+        assert!(price(-5, 0, -2, NOW).validate(NOW, 60, 100).is_err());
+        assert!(price(10_000, 100, -2, NOW - 61)
+            .validate(NOW, 60, 100)
+            .is_err());
+        // 1% confidence is the limit at 100 bps
+        assert!(price(10_000, 101, -2, NOW).validate(NOW, 60, 100).is_err());
+    }
+
+    #[test]
+    fn value_of_scales_between_decimals() {
+        // 150.00 quote per token, expo -2
+        let sol = price(15_000, 0, -2, NOW);
+        // 2 tokens with 9 decimals into a 6 decimal quote
+        assert_eq!(sol.value_of(2_000_000_000, 9, 6).unwrap(), 300_000_000);
+        // rounds down below one quote base unit
+        assert_eq!(sol.value_of(1, 9, 6).unwrap(), 0);
+
+        // 2.5 quote per token of an asset without decimals scales up
+        let token = price(25, 0, -1, NOW);
+        assert_eq!(token.value_of(4, 0, 6).unwrap(), 10_000_000);
+    }
+}
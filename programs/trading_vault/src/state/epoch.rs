@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+// Deposits and redemptions queued during one epoch, settled together at the NAV struck at its close
+#[account]
+pub struct Epoch {
+    pub vault: Pubkey,
+    pub epoch_id: u64,
+    // quote tokens queued for deposit
+    pub total_deposits: u64,
+    // bonds queued for redemption, held in the vault's escrow
+    pub total_redemptions: u64,
+    // cost basis released by the queued redemptions
+    pub total_redeemed_deposit_value: u64,
+    // the deposits were left unpriced at settlement and carried into a later epoch
+    pub deposits_carried: bool,
+    // deposits of epochs carried_from.. carried into this one and priced with it
+    pub carried_from: u64,
+    pub carried_deposits: u64,
+
+    pub is_settled: bool,
+    pub settled_at: i64,
+    // NAV per bond (scaled by NAV_PRECISION) struck at settlement
+    pub nav: u128,
+    // deposits net of the entry fee, carried deposits included
+    pub net_deposits: u64,
+    pub bonds_minted: u64,
+    // quote tokens owed to redeemers, net of the exit fee
+    pub net_payout: u64,
+    pub bump: u8,
+}
+
+impl Epoch {
+    pub const LEN: usize = std::mem::size_of::<Epoch>() + 8;
+}
+
+// A depositor's queued requests in one epoch
+#[account]
+pub struct EpochClaim {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    pub epoch_id: u64,
+    pub deposit_amount: u64,
+    pub redeem_bond_amount: u64,
+    pub bump: u8,
+}
+
+impl EpochClaim {
+    pub const LEN: usize = std::mem::size_of::<EpochClaim>() + 8;
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct LeaderProfile {
+    pub leader: Pubkey,
+    pub vault_count: u64,
+    pub bump: u8,
+}
+
+impl LeaderProfile {
+    pub const LEN: usize = std::mem::size_of::<LeaderProfile>() + 8;
+}
@@ -1,5 +1,26 @@
+pub mod leader_profile;
+pub use leader_profile::*;
+
 pub mod user;
 pub use user::*;
 
 pub mod vault;
-pub use vault::*;
\ No newline at end of file
+pub use vault::*;
+
+pub mod protocol_config;
+pub use protocol_config::*;
+
+pub mod withdraw_request;
+pub use withdraw_request::*;
+
+pub mod epoch;
+pub use epoch::*;
+
+pub mod position;
+pub use position::*;
+
+pub mod param_change;
+pub use param_change::*;
+
+pub mod proposal;
+pub use proposal::*;
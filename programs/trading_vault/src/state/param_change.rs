@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::VaultError, Vault, MAX_ENTRY_EXIT_FEE_BPS, MAX_MANAGEMENT_FEE_BPS,
+    MAX_PERFORMANCE_FEE_BPS, MAX_STRATEGY_ID_LEN,
+};
+
+// Changes to the terms depositors joined under, applied only after the vault's delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ParamChange {
+    Fees {
+        performance_fee_bps: u16,
+        management_fee_bps: u16,
+        entry_fee_bps: u16,
+        exit_fee_bps: u16,
+    },
+    LockPeriod {
+        lock_period_secs: i64,
+    },
+    Strategy {
+        strategy_id: String,
+    },
+    BackendWallet {
+        backend_wallet: Pubkey,
+    },
+    // winds the vault down, see Vault::terminate
+    Terminate,
+}
+
+impl ParamChange {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ParamChange::Fees {
+                performance_fee_bps,
+                management_fee_bps,
+                entry_fee_bps,
+                exit_fee_bps,
+            } => {
+                require!(
+                    *performance_fee_bps <= MAX_PERFORMANCE_FEE_BPS
+                        && *management_fee_bps <= MAX_MANAGEMENT_FEE_BPS
+                        && *entry_fee_bps <= MAX_ENTRY_EXIT_FEE_BPS
+                        && *exit_fee_bps <= MAX_ENTRY_EXIT_FEE_BPS,
+                    VaultError::InvalidFeeRate
+                );
+            }
+            ParamChange::LockPeriod { lock_period_secs } => {
+                require!(*lock_period_secs >= 0, VaultError::InvalidLockPeriod);
+            }
+            ParamChange::Strategy { strategy_id } => {
+                require!(
+                    strategy_id.len() <= MAX_STRATEGY_ID_LEN,
+                    VaultError::StrategyIdTooLong
+                );
+            }
+            ParamChange::BackendWallet { .. } | ParamChange::Terminate => {}
+        }
+        Ok(())
+    }
+}
+
+#[account]
+pub struct PendingParamChange {
+    pub vault: Pubkey,
+    pub change: ParamChange,
+    // queued by a passed proposal: the leader can neither cancel it nor hold back its execution
+    pub by_governance: bool,
+    pub queued_at: i64,
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl PendingParamChange {
+    // sized for the largest change, a maximum length strategy id
+    pub const LEN: usize = 8 + 32 + (1 + 4 + MAX_STRATEGY_ID_LEN) + 1 + 8 + 8 + 1;
+
+    // Queues `change` behind the vault's delay and opens the exit window until it is executed or cancelled
+    pub fn queue(
+        &mut self,
+        vault_key: Pubkey,
+        vault: &mut Vault,
+        change: ParamChange,
+        by_governance: bool,
+        bump: u8,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(!vault.is_terminated, VaultError::VaultTerminated);
+        change.validate()?;
+
+        self.vault = vault_key;
+        self.change = change;
+        self.by_governance = by_governance;
+        self.queued_at = current_time;
+        self.executable_at = current_time.saturating_add(vault.param_change_delay_secs);
+        self.bump = bump;
+
+        vault.param_change_executable_at = self.executable_at;
+        Ok(())
+    }
+}
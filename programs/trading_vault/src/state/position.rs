@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    accounting::{mul_div, Rounding},
+    error::VaultError,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+#[account]
+pub struct Position {
+    pub vault: Pubkey,
+    // market the position is open on, e.g. the DEX's perp market account
+    pub market: Pubkey,
+    pub side: PositionSide,
+    // size in base units of the traded asset
+    pub size: u64,
+    pub base_decimals: u8,
+    // prices in quote base units per whole base token
+    pub entry_price: u64,
+    pub mark_price: u64,
+    // quote tokens posted as margin for the position
+    pub collateral: u64,
+    pub opened_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Position {
+    pub const LEN: usize = std::mem::size_of::<Position>() + 8;
+
+    // Collateral plus unrealized PnL at the mark price, floored at zero
+    pub fn equity(&self) -> Result<u64> {
+        let price_change = self.mark_price.abs_diff(self.entry_price);
+        let pnl = mul_div(
+            self.size as u128,
+            price_change as u128,
+            10u128.pow(self.base_decimals as u32),
+            Rounding::Down,
+        )?;
+        let pnl = u64::try_from(pnl).map_err(|_| VaultError::MathOverflow)?;
+
+        let is_profit = match self.side {
+            PositionSide::Long => self.mark_price >= self.entry_price,
+            PositionSide::Short => self.mark_price <= self.entry_price,
+        };
+        if is_profit {
+            self.collateral
+                .checked_add(pnl)
+                .ok_or(VaultError::MathOverflow.into())
+        } else {
+            Ok(self.collateral.saturating_sub(pnl))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2 whole base tokens (6 decimals) entered at 100 quote units each, on 50 of collateral
+    fn position(side: PositionSide, mark_price: u64) -> Position {
+        Position {
+            vault: Pubkey::default(),
+            market: Pubkey::default(),
+            side,
+            size: 2_000_000,
+            base_decimals: 6,
+            entry_price: 100,
+            mark_price,
+            collateral: 50,
+            opened_at: 0,
+            updated_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn long_gains_when_the_mark_rises() {
+        assert_eq!(position(PositionSide::Long, 110).equity().unwrap(), 70);
+        assert_eq!(position(PositionSide::Long, 90).equity().unwrap(), 30);
+    }
+
+    #[test]
+    fn short_gains_when_the_mark_falls() {
+        assert_eq!(position(PositionSide::Short, 90).equity().unwrap(), 70);
+        assert_eq!(position(PositionSide::Short, 110).equity().unwrap(), 30);
+    }
+
+    #[test]
+    fn equity_is_collateral_at_the_entry_price() {
+        assert_eq!(position(PositionSide::Long, 100).equity().unwrap(), 50);
+        assert_eq!(position(PositionSide::Short, 100).equity().unwrap(), 50);
+    }
+
+    #[test]
+    fn losses_beyond_the_collateral_floor_equity_at_zero() {
+        assert_eq!(position(PositionSide::Long, 60).equity().unwrap(), 0);
+        assert_eq!(position(PositionSide::Short, 140).equity().unwrap(), 0);
+    }
+
+    #[test]
+    fn pnl_rounds_down_on_fractional_sizes() {
+        let mut position = position(PositionSide::Long, 103);
+        position.size = 1_500_000;
+        // 1.5 * 3 = 4.5 quote units of profit
+        assert_eq!(position.equity().unwrap(), 54);
+    }
+}
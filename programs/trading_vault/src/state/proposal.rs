@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum ProposalAction {
+    ReplaceLeader {
+        new_leader: Pubkey,
+    },
+    ChangeFees {
+        performance_fee_bps: u16,
+        management_fee_bps: u16,
+        entry_fee_bps: u16,
+        exit_fee_bps: u16,
+    },
+    // winds the vault down to withdrawals only, without lock periods
+    TerminateVault,
+}
+
+#[account]
+pub struct Proposal {
+    pub vault: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: ProposalAction,
+    // bond supply when the proposal was created, the base of the quorum
+    pub snapshot_supply: u64,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub is_executed: bool,
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const LEN: usize = std::mem::size_of::<Proposal>() + 8;
+}
+
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub approve: bool,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const LEN: usize = std::mem::size_of::<VoteRecord>() + 8;
+}
+
+// Bonds a holder keeps locked for voting. Only bonds locked before a proposal was created vote on it,
+// so bonds deposited, bought or moved in afterwards carry no weight.
+#[account]
+pub struct VoteLock {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    // when bonds were last added to the lock
+    pub last_locked_at: i64,
+    pub bump: u8,
+}
+
+impl VoteLock {
+    pub const LEN: usize = std::mem::size_of::<VoteLock>() + 8;
+}
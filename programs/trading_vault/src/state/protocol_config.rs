@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::VaultError, ProtocolConfigParams, BPS_DENOMINATOR, MAX_QUOTE_MINTS, MAX_SWAP_PROGRAMS,
+};
+
+#[account]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,
+    // owner of the token accounts receiving the protocol's cut of vault fees
+    pub treasury: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub allowed_quote_mints: Vec<Pubkey>,
+    // DEX and aggregator programs vaults may swap through
+    pub allowed_swap_programs: Vec<Pubkey>,
+    // program that must own every price account asset oracles are read from
+    pub oracle_program: Pubkey,
+    pub is_paused: bool,
+    pub bump: u8,
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize =
+        8 + 32 + 32 + 2 + (4 + 32 * MAX_QUOTE_MINTS) + (4 + 32 * MAX_SWAP_PROGRAMS) + 32 + 1 + 1;
+
+    pub fn is_quote_mint_allowed(&self, mint: &Pubkey) -> bool {
+        self.allowed_quote_mints.contains(mint)
+    }
+
+    pub fn is_swap_program_allowed(&self, program: &Pubkey) -> bool {
+        self.allowed_swap_programs.contains(program)
+    }
+
+    pub fn apply(&mut self, params: ProtocolConfigParams) -> Result<()> {
+        require!(
+            params.protocol_fee_bps as u64 <= BPS_DENOMINATOR,
+            VaultError::InvalidFeeRate
+        );
+        require!(
+            params.allowed_quote_mints.len() <= MAX_QUOTE_MINTS,
+            VaultError::TooManyQuoteMints
+        );
+        require!(
+            params.allowed_swap_programs.len() <= MAX_SWAP_PROGRAMS,
+            VaultError::TooManySwapPrograms
+        );
+
+        self.treasury = params.treasury;
+        self.protocol_fee_bps = params.protocol_fee_bps;
+        self.allowed_quote_mints = params.allowed_quote_mints;
+        self.allowed_swap_programs = params.allowed_swap_programs;
+        self.oracle_program = params.oracle_program;
+        self.is_paused = params.is_paused;
+        Ok(())
+    }
+}
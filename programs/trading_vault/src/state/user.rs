@@ -5,6 +5,7 @@ pub struct User {
     pub bond_amount: u64,
     pub deposit_value: u64,
     pub deposit_time: i64,
+    pub bump: u8,
 }
 
 impl User {
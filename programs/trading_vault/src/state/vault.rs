@@ -1,5 +1,46 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Transfer;
+use anchor_spl::token::{Burn, MintTo, Transfer};
+
+use crate::{
+    accounting::{self, Rounding},
+    error::VaultError,
+    events::DrawdownCircuitBreakerTripped,
+    User, BPS_DENOMINATOR, MAX_STRATEGY_ID_LEN, MAX_VAULT_ASSETS, PAUSE_DEPOSITS,
+    PAUSE_NAV_REPORTING, PAUSE_TRADING, PAUSE_WITHDRAWALS, WITHDRAW_ONLY,
+};
+
+// Token accounts an exit fee is paid from and to
+pub struct ExitFeeAccounts<'info> {
+    pub vault_pay_token_account: AccountInfo<'info>,
+    pub fee_recipient: AccountInfo<'info>,
+    pub protocol_treasury: AccountInfo<'info>,
+    pub vault_authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct VaultAsset {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    // Pyth-compatible price account valuing the asset in quote terms
+    pub oracle: Pubkey,
+    // vault-authority-owned token account holding the asset
+    pub custody: Pubkey,
+}
+
+impl VaultAsset {
+    pub const LEN: usize = 32 + 1 + 32 + 32;
+}
+
+// Least-privilege roles: the leader configures the vault, the operator (backend wallet) trades and reports,
+// the guardian can only halt, and the fee manager handles fee payouts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Leader,
+    Operator,
+    Guardian,
+    FeeManager,
+}
 
 #[account]
 pub struct Vault {
@@ -9,27 +50,622 @@ pub struct Vault {
     pub tvl: u64,
     pub deposit_value: u64,
     pub leader: Pubkey,
-    pub is_trading_paused: bool,
+    // leader at creation, fixed so the vault address survives leadership transfers
+    pub creator: Pubkey,
+    // leader proposed by the current one, Pubkey::default() when none
+    pub pending_leader: Pubkey,
+    pub index: u64,
+    // PAUSE_* flags of the operations currently halted
+    pub pause_flags: u8,
+    // may pause the vault and revoke the backend wallet, but not resume it or move funds
+    pub guardian: Pubkey,
+    pub lock_period_secs: i64,
+    // minimum delay between queueing and executing a change of terms
+    pub param_change_delay_secs: i64,
+    // when the queued change of terms becomes executable, 0 if none is queued
+    pub param_change_executable_at: i64,
+    // when withdrawals were last resumed after a pause, a change queued before it restarts its delay
+    pub withdrawals_resumed_at: i64,
+    // set once bond holders or the leader wind the vault down; it only unwinds into cash from then on
+    pub is_terminated: bool,
+
+    // bond-holder governance: quorum of the snapshot supply and share of votes cast needed to pass
+    pub governance_quorum_bps: u16,
+    pub governance_threshold_bps: u16,
+    pub governance_voting_period_secs: i64,
+    pub proposal_count: u64,
+    // deposit caps in quote units, 0 meaning uncapped
+    pub max_tvl: u64,
+    pub max_deposit_per_user: u64,
+    // private vaults only accept depositors proven against the allowlist root
+    pub is_private: bool,
+    pub allowlist_root: [u8; 32],
+
+    // forward pricing: requests queue during an epoch and settle at its closing NAV, 0 disables
+    pub epoch_length_secs: i64,
+    pub current_epoch: u64,
+    pub epoch_started_at: i64,
+    // quote tokens queued for deposit and not yet settled, not yet part of tvl
+    pub pending_deposits: u64,
+    // deposits of epochs settled while deposits were paused, priced at the next full settlement
+    pub carried_deposits: u64,
+    // first epoch whose deposits are in carried_deposits
+    pub carried_from_epoch: u64,
+
+    pub performance_fee_bps: u16,
+    // NAV per bond (scaled by NAV_PRECISION) at which performance fees were last charged
+    pub high_water_mark: u128,
+    pub management_fee_bps: u16,
+    pub last_fee_accrual_ts: i64,
+    // fees owed to the leader, held in the vault until claimed
+    pub accrued_fees: u64,
 
+    pub entry_fee_bps: u16,
+    pub exit_fee_bps: u16,
+    // quote token account receiving entry and exit fees
+    pub fee_recipient: Pubkey,
+    // claims fees and chooses where they are paid
+    pub fee_manager: Pubkey,
+
+    // Pubkey::default() once revoked
     pub backend_wallet: Pubkey,
+    // equity of off-chain trading positions reported by the backend, included in tvl
+    pub position_equity: u64,
+    pub position_count: u8,
+    pub last_nav_report_ts: i64,
+    // equity summed from the vault's position accounts, included in tvl
+    pub positions_value: u64,
+    pub last_positions_sync_ts: i64,
+    pub max_nav_change_bps: u16,
+    pub min_nav_report_interval_secs: i64,
+
+    // NAV drawdown from the peak that pauses trading, 0 disables the circuit breaker
+    pub max_drawdown_bps: u16,
+    // highest NAV per bond (scaled by NAV_PRECISION) since trading last resumed
+    pub peak_nav: u128,
+    pub drawdown_cooldown_secs: i64,
+    // when the circuit breaker last paused trading, 0 if it is not tripped
+    pub drawdown_tripped_at: i64,
+
+    pub assets: [VaultAsset; MAX_VAULT_ASSETS],
+    pub asset_count: u8,
+    // oracle value of the custody balances, included in tvl
+    pub holdings_value: u64,
+    pub last_holdings_refresh_ts: i64,
+    pub oracle_max_staleness_secs: i64,
+    pub oracle_max_conf_bps: u16,
 
     pub vault_authority: Pubkey,
     pub vault_authority_bump: u8,
+
+    pub quote_mint: Pubkey,
+    pub quote_decimals: u8,
+    // vault-authority-owned quote token account holding idle cash
+    pub vault_pay_token_account: Pubkey,
+
+    pub bond_mint: Pubkey,
+    pub mint_bump: u8,
+    pub bump: u8,
 }
 
 impl Vault {
-    pub const LEN: usize = std::mem::size_of::<Vault>() + 8;
+    // Borsh sizes field by field, with room for a maximum length strategy id
+    pub const LEN: usize = 8
+        + (4 + MAX_STRATEGY_ID_LEN)
+        // bond_price, bond_supply, tvl, deposit_value
+        + 8 * 4
+        // leader, creator, pending_leader, index, pause_flags, guardian
+        + 32 * 3 + 8 + 1 + 32
+        // lock period, param change delay and executable_at, withdrawals resumed, is_terminated
+        + 8 * 4 + 1
+        // governance
+        + 2 + 2 + 8 + 8
+        // deposit caps and allowlist
+        + 8 + 8 + 1 + 32
+        // epochs, carried deposits
+        + 8 * 4 + 8 * 2
+        // performance fee, high-water mark, management fee, last accrual, accrued fees
+        + 2 + 16 + 2 + 8 + 8
+        // entry and exit fees, fee recipient, fee manager
+        + 2 + 2 + 32 + 32
+        // backend wallet, reported equity, position count, last report, positions value, last sync
+        + 32 + 8 + 1 + 8 + 8 + 8
+        // NAV report bounds
+        + 2 + 8
+        // drawdown breaker
+        + 2 + 16 + 8 + 8
+        // assets, holdings and oracle bounds
+        + VaultAsset::LEN * MAX_VAULT_ASSETS + 1 + 8 + 8 + 8 + 2
+        // vault authority, quote mint, pay account, bond mint and bumps
+        + 32 + 1 + 32 + 1 + 32 + 32 + 1 + 1;
+
+    // Assets attributable to bond holders, excluding fees owed to the leader
+    pub fn total_assets(&self) -> u64 {
+        self.tvl.saturating_sub(self.accrued_fees)
+    }
+
+    // Idle quote cash backing the bonds: everything not held in custody or positions, net of fees owed
+    pub fn realised_assets(&self) -> u64 {
+        self.total_assets().saturating_sub(
+            self.holdings_value
+                .saturating_add(self.position_equity)
+                .saturating_add(self.positions_value),
+        )
+    }
+
+    // Assets redemptions are priced against. A terminated vault pays each exit its pro-rata share of the
+    // cash realised so far, so the first exits cannot drain the liquid funds while positions unwind.
+    pub fn redeemable_assets(&self) -> u64 {
+        if self.is_terminated {
+            self.realised_assets()
+        } else {
+            self.total_assets()
+        }
+    }
+
+    // Accrues the management fee for the time elapsed since the last accrual, then the performance fee
+    pub fn accrue_fees(&mut self, current_time: i64) -> Result<()> {
+        let elapsed = current_time.saturating_sub(self.last_fee_accrual_ts).max(0) as u64;
+        let fee =
+            accounting::management_fee(self.total_assets(), self.management_fee_bps, elapsed)?;
+
+        self.accrued_fees = self
+            .accrued_fees
+            .checked_add(fee)
+            .ok_or(VaultError::MathOverflow)?;
+        self.last_fee_accrual_ts = current_time;
+
+        self.crystallize_performance_fee()
+    }
+
+    // Charges the performance fee on any NAV above the high-water mark and raises the mark
+    pub fn crystallize_performance_fee(&mut self) -> Result<()> {
+        if self.bond_supply == 0 {
+            self.high_water_mark = accounting::NAV_PRECISION;
+            return Ok(());
+        }
+
+        let nav = accounting::nav_per_share(self.total_assets(), self.bond_supply)?;
+        if nav <= self.high_water_mark {
+            return Ok(());
+        }
+
+        let fee = accounting::performance_fee(
+            nav,
+            self.high_water_mark,
+            self.bond_supply,
+            self.performance_fee_bps,
+        )?;
+
+        self.accrued_fees = self
+            .accrued_fees
+            .checked_add(fee)
+            .ok_or(VaultError::MathOverflow)?;
+        self.high_water_mark = accounting::nav_per_share(self.total_assets(), self.bond_supply)?;
+        Ok(())
+    }
+
+    // Replaces the backend-reported equity of off-chain positions
+    pub fn apply_position_equity(
+        &mut self,
+        vault_key: Pubkey,
+        position_equity: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        self.update_tvl_component(
+            vault_key,
+            self.position_equity,
+            position_equity,
+            self.last_nav_report_ts,
+            current_time,
+        )?;
+        self.position_equity = position_equity;
+        self.last_nav_report_ts = current_time;
+        Ok(())
+    }
+
+    // Replaces the equity summed from the vault's position accounts
+    pub fn apply_positions_value(
+        &mut self,
+        vault_key: Pubkey,
+        positions_value: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        self.update_tvl_component(
+            vault_key,
+            self.positions_value,
+            positions_value,
+            self.last_positions_sync_ts,
+            current_time,
+        )?;
+        self.positions_value = positions_value;
+        self.last_positions_sync_ts = current_time;
+        Ok(())
+    }
+
+    // Replaces the oracle value of the assets held in custody accounts
+    pub fn apply_holdings_value(
+        &mut self,
+        vault_key: Pubkey,
+        holdings_value: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        self.update_tvl_component(
+            vault_key,
+            self.holdings_value,
+            holdings_value,
+            self.last_holdings_refresh_ts,
+            current_time,
+        )?;
+        self.holdings_value = holdings_value;
+        self.last_holdings_refresh_ts = current_time;
+        Ok(())
+    }
+
+    // Swaps one valuation component of tvl for a new one, bounded by `max_nav_change_bps` of the current tvl.
+    // Each component keeps its own update time so one source can never starve another.
+    fn update_tvl_component(
+        &mut self,
+        vault_key: Pubkey,
+        previous: u64,
+        next: u64,
+        last_update_ts: i64,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(
+            current_time >= last_update_ts.saturating_add(self.min_nav_report_interval_secs),
+            VaultError::NavReportTooFrequent
+        );
+
+        // management fee is charged on the assets held before this report
+        self.accrue_fees(current_time)?;
+
+        let previous_tvl = self.tvl;
+        let new_tvl = self
+            .tvl
+            .saturating_sub(previous)
+            .checked_add(next)
+            .ok_or(VaultError::MathOverflow)?;
+
+        let max_change = accounting::mul_div(
+            previous_tvl as u128,
+            self.max_nav_change_bps as u128,
+            BPS_DENOMINATOR as u128,
+            accounting::Rounding::Down,
+        )?;
+        require!(
+            (new_tvl.abs_diff(previous_tvl) as u128) <= max_change,
+            VaultError::NavChangeTooLarge
+        );
+
+        self.tvl = new_tvl;
+
+        self.crystallize_performance_fee()?;
+        self.refresh_bond_price()?;
+        self.check_drawdown(vault_key, current_time)
+    }
+
+    // Raises the peak NAV, or pauses trading once the NAV falls more than `max_drawdown_bps` below it.
+    // Trades reach the NAV through holdings refreshes and position reports, so this runs on every one of them.
+    pub fn check_drawdown(&mut self, vault_key: Pubkey, current_time: i64) -> Result<()> {
+        if self.bond_supply == 0 {
+            self.peak_nav = accounting::NAV_PRECISION;
+            return Ok(());
+        }
+
+        let nav = accounting::nav_per_share(self.total_assets(), self.bond_supply)?;
+        if nav >= self.peak_nav {
+            self.peak_nav = nav;
+            return Ok(());
+        }
+        if self.max_drawdown_bps == 0 || self.drawdown_tripped_at != 0 {
+            return Ok(());
+        }
+
+        let drawdown_bps = accounting::mul_div(
+            self.peak_nav - nav,
+            BPS_DENOMINATOR as u128,
+            self.peak_nav,
+            accounting::Rounding::Down,
+        )? as u64;
+        if drawdown_bps > self.max_drawdown_bps as u64 {
+            self.pause_flags |= PAUSE_TRADING;
+            self.drawdown_tripped_at = current_time;
+
+            emit!(DrawdownCircuitBreakerTripped {
+                vault: vault_key,
+                nav,
+                peak_nav: self.peak_nav,
+                drawdown_bps,
+                timestamp: current_time,
+            });
+        }
+        Ok(())
+    }
+
+    // Re-arms a tripped circuit breaker from the current NAV once its cooldown has passed.
+    // An untripped breaker keeps its peak, so resuming after an ordinary pause never forgives a drawdown.
+    pub fn reset_drawdown(&mut self, current_time: i64) -> Result<()> {
+        if self.drawdown_tripped_at == 0 {
+            return Ok(());
+        }
+        require!(
+            current_time
+                >= self
+                    .drawdown_tripped_at
+                    .saturating_add(self.drawdown_cooldown_secs),
+            VaultError::DrawdownCooldownActive
+        );
+        self.drawdown_tripped_at = 0;
+        self.peak_nav = accounting::nav_per_share(self.total_assets(), self.bond_supply)?;
+        Ok(())
+    }
+
+    // The fee manager defaults to the leader, so it follows leadership unless it was delegated elsewhere
+    pub fn hand_over_leadership(&mut self, new_leader: Pubkey) {
+        if self.fee_manager == self.leader {
+            self.fee_manager = new_leader;
+        }
+        self.leader = new_leader;
+    }
+
+    // A leader voted out by bond holders keeps none of the roles it held or handed out. Its backend
+    // wallet is revoked and fees are routed to vault_pay until the new fee manager picks a recipient.
+    pub fn replace_leader(&mut self, new_leader: Pubkey) {
+        self.leader = new_leader;
+        self.pending_leader = Pubkey::default();
+        self.fee_manager = new_leader;
+        self.guardian = Pubkey::default();
+        self.backend_wallet = Pubkey::default();
+        self.fee_recipient = self.vault_pay_token_account;
+    }
+
+    // No fee recipient is set while fees are routed to vault_pay itself
+    pub fn holds_fees(&self) -> bool {
+        self.fee_recipient == self.vault_pay_token_account
+    }
+
+    // Without a fee recipient the leader's share of an entry or exit fee stays in the vault for the bond holders
+    pub fn keep_leader_fee(&mut self, leader_fee: u64) -> Result<()> {
+        self.tvl = self
+            .tvl
+            .checked_add(leader_fee)
+            .ok_or(VaultError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn role_holder(&self, role: Role) -> Pubkey {
+        match role {
+            Role::Leader => self.leader,
+            Role::Operator => self.backend_wallet,
+            Role::Guardian => self.guardian,
+            Role::FeeManager => self.fee_manager,
+        }
+    }
+
+    // Unassigned roles hold Pubkey::default(), which can never sign
+    pub fn has_any_role(&self, key: &Pubkey, roles: &[Role]) -> bool {
+        roles.iter().any(|role| self.role_holder(*role) == *key)
+    }
+
+    pub fn registered_assets(&self) -> &[VaultAsset] {
+        &self.assets[..self.asset_count as usize]
+    }
+
+    // Token accounts the vault authority may trade from or into: the pay account and registered custodies
+    pub fn is_vault_token_account(&self, key: &Pubkey) -> bool {
+        *key == self.vault_pay_token_account
+            || self.registered_assets().iter().any(|a| a.custody == *key)
+    }
+
+    // Books a closed position: the equity it was last carried at leaves positions_value and tvl,
+    // and the proceeds paid back into vault_pay enter tvl as idle cash
+    pub fn book_position_close(
+        &mut self,
+        vault_key: Pubkey,
+        equity: u64,
+        proceeds: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        self.accrue_fees(current_time)?;
+
+        let released = equity.min(self.positions_value);
+        self.positions_value -= released;
+        self.tvl = self
+            .tvl
+            .saturating_sub(released)
+            .checked_add(proceeds)
+            .ok_or(VaultError::MathOverflow)?;
+
+        self.crystallize_performance_fee()?;
+        self.refresh_bond_price()?;
+        self.check_drawdown(vault_key, current_time)
+    }
+
+    pub fn asset_by_custody(&self, custody: &Pubkey) -> Option<&VaultAsset> {
+        self.registered_assets()
+            .iter()
+            .find(|a| a.custody == *custody)
+    }
+
+    // Books a swap at its quote value: what was spent leaves tvl and what was received enters it.
+    // Custody legs also move holdings_value, so the next holdings refresh starts from the traded balances.
+    pub fn book_swap(
+        &mut self,
+        vault_key: Pubkey,
+        spent_value: u64,
+        spent_from_custody: bool,
+        received_value: u64,
+        received_into_custody: bool,
+        current_time: i64,
+    ) -> Result<()> {
+        // management fee is charged on the assets held before the swap
+        self.accrue_fees(current_time)?;
+
+        // a custody leg can never release more than the holdings it was last valued at
+        let spent_value = if spent_from_custody {
+            let spent_value = spent_value.min(self.holdings_value);
+            self.holdings_value -= spent_value;
+            spent_value
+        } else {
+            spent_value
+        };
+        if received_into_custody {
+            self.holdings_value = self
+                .holdings_value
+                .checked_add(received_value)
+                .ok_or(VaultError::MathOverflow)?;
+        }
+
+        self.tvl = self
+            .tvl
+            .saturating_sub(spent_value)
+            .checked_add(received_value)
+            .ok_or(VaultError::MathOverflow)?;
+
+        self.crystallize_performance_fee()?;
+        self.refresh_bond_price()?;
+        self.check_drawdown(vault_key, current_time)
+    }
+
+    pub fn check_deposit_caps(&self, user_deposit_value: u64, amount: u64) -> Result<()> {
+        if self.max_tvl > 0 {
+            require!(
+                self.tvl
+                    .saturating_add(self.pending_deposits)
+                    .saturating_add(amount)
+                    <= self.max_tvl,
+                VaultError::VaultCapExceeded
+            );
+        }
+        if self.max_deposit_per_user > 0 {
+            require!(
+                user_deposit_value.saturating_add(amount) <= self.max_deposit_per_user,
+                VaultError::UserDepositCapExceeded
+            );
+        }
+        Ok(())
+    }
+
+    // Fails with the error of `flag` if that operation is paused
+    pub fn check_not_paused(&self, flag: u8) -> Result<()> {
+        if self.pause_flags & flag == 0 {
+            return Ok(());
+        }
+        match flag {
+            PAUSE_DEPOSITS => err!(VaultError::DepositsPaused),
+            PAUSE_WITHDRAWALS => err!(VaultError::WithdrawalsPaused),
+            PAUSE_TRADING => err!(VaultError::TradingPaused),
+            PAUSE_NAV_REPORTING => err!(VaultError::NavReportingPaused),
+            _ => err!(VaultError::InvalidPauseFlags),
+        }
+    }
+
+    pub fn uses_epochs(&self) -> bool {
+        self.epoch_length_secs > 0
+    }
+
+    // Lock periods are waived while a change of terms is queued so depositors can exit before it applies
+    pub fn is_locked(&self, deposit_time: i64, current_time: i64) -> bool {
+        self.param_change_executable_at == 0
+            && current_time < deposit_time.saturating_add(self.lock_period_secs)
+    }
+
+    // Depositors must have had the whole delay to withdraw, so a change queued before withdrawals were
+    // last paused becomes executable only a full delay after they resumed
+    pub fn check_exit_window(
+        &self,
+        queued_at: i64,
+        executable_at: i64,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(
+            self.pause_flags & PAUSE_WITHDRAWALS == 0,
+            VaultError::ExitWindowInterrupted
+        );
+        require!(
+            self.withdrawals_resumed_at <= queued_at
+                || current_time
+                    >= self
+                        .withdrawals_resumed_at
+                        .saturating_add(executable_at - queued_at),
+            VaultError::ExitWindowInterrupted
+        );
+        Ok(())
+    }
+
+    // Winds the vault down: nothing new comes in and depositors can leave without a lock period
+    pub fn terminate(&mut self) {
+        self.is_terminated = true;
+        self.pause_flags |= WITHDRAW_ONLY;
+        self.lock_period_secs = 0;
+    }
+
+    pub fn refresh_bond_price(&mut self) -> Result<()> {
+        self.bond_price = accounting::bond_price(self.total_assets(), self.bond_supply)?;
+        Ok(())
+    }
+
+    // Cost basis released proportionally to the bonds redeemed, rounded up in
+    // favour of the remaining holders
+    pub fn released_deposit_value(user: &User, bond_amount: u64) -> Result<u64> {
+        Ok(accounting::mul_div(
+            user.deposit_value as u128,
+            bond_amount as u128,
+            user.bond_amount as u128,
+            Rounding::Up,
+        )? as u64)
+    }
+
+    // Splits an exit fee between the leader's fee recipient and the protocol
+    // treasury and pays both shares out of vault_pay
+    pub fn pay_exit_fee(
+        &mut self,
+        vault_key: Pubkey,
+        accounts: ExitFeeAccounts,
+        exit_fee: u64,
+        protocol_fee_bps: u16,
+    ) -> Result<()> {
+        let (leader_fee, protocol_fee) = accounting::split_fee(exit_fee, protocol_fee_bps)?;
+        if leader_fee > 0 && self.holds_fees() {
+            self.keep_leader_fee(leader_fee)?;
+        } else if leader_fee > 0 {
+            self.transfer_tokens(
+                vault_key,
+                accounts.vault_pay_token_account.clone(),
+                accounts.fee_recipient,
+                accounts.vault_authority.clone(),
+                accounts.token_program.clone(),
+                leader_fee,
+            )?;
+        }
+        if protocol_fee > 0 {
+            self.transfer_tokens(
+                vault_key,
+                accounts.vault_pay_token_account,
+                accounts.protocol_treasury,
+                accounts.vault_authority,
+                accounts.token_program,
+                protocol_fee,
+            )?;
+        }
+        Ok(())
+    }
 
     pub fn transfer_tokens<'info>(
         &self,
+        vault_key: Pubkey,
         from: AccountInfo<'info>,
         to: AccountInfo<'info>,
         authority: AccountInfo<'info>,
         token_program: AccountInfo<'info>,
         amount: u64,
     ) -> Result<()> {
-        let authority_seeds: &[&[&[u8]]] =
-            &[&[b"vault_authority", &[self.vault_authority_bump]]];
+        let authority_seeds: &[&[&[u8]]] = &[&[
+            b"vault_authority",
+            vault_key.as_ref(),
+            &[self.vault_authority_bump],
+        ]];
 
         let context = CpiContext::new(
             token_program,
@@ -62,4 +698,264 @@ impl Vault {
         );
         anchor_spl::token::transfer(context, amount)
     }
+
+    pub fn mint_bonds<'info>(
+        &self,
+        vault_key: Pubkey,
+        mint: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let mint_seeds: &[&[&[u8]]] = &[&[b"mint", vault_key.as_ref(), &[self.mint_bump]]];
+
+        let context = CpiContext::new(
+            token_program,
+            MintTo {
+                mint: mint.clone(),
+                to,
+                authority: mint,
+            },
+        )
+        .with_signer(mint_seeds);
+
+        anchor_spl::token::mint_to(context, amount)
+    }
+
+    pub fn burn_bonds<'info>(
+        &self,
+        vault_key: Pubkey,
+        mint: AccountInfo<'info>,
+        from: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        // bonds may be burnt from escrow accounts owned by the vault authority
+        let signer_seeds: &[&[&[u8]]] = &[
+            &[b"mint", vault_key.as_ref(), &[self.mint_bump]],
+            &[
+                b"vault_authority",
+                vault_key.as_ref(),
+                &[self.vault_authority_bump],
+            ],
+        ];
+
+        let context = CpiContext::new(
+            token_program,
+            Burn {
+                mint,
+                from,
+                authority,
+            },
+        )
+        .with_signer(signer_seeds);
+
+        anchor_spl::token::burn(context, amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SECONDS_PER_YEAR;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn vault(tvl: u64, holdings_value: u64) -> Vault {
+        let mut vault = Vault::deserialize(&mut &vec![0u8; Vault::LEN][..]).unwrap();
+        vault.tvl = tvl;
+        vault.holdings_value = holdings_value;
+        vault.bond_supply = tvl;
+        vault.last_fee_accrual_ts = NOW;
+        vault.high_water_mark = accounting::NAV_PRECISION;
+        vault.peak_nav = accounting::NAV_PRECISION;
+        vault
+    }
+
+    #[test]
+    fn len_fits_a_maximum_length_strategy_id() {
+        let mut vault = vault(0, 0);
+        vault.strategy_id = "s".repeat(MAX_STRATEGY_ID_LEN);
+
+        let mut data = Vec::new();
+        vault.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Vault::LEN);
+    }
+
+    #[test]
+    fn a_withdrawal_pause_restarts_the_delay_of_a_queued_change() {
+        let mut vault = vault(0, 0);
+        let (queued_at, executable_at) = (NOW, NOW + 1_000);
+
+        vault
+            .check_exit_window(queued_at, executable_at, executable_at)
+            .unwrap();
+
+        vault.pause_flags |= PAUSE_WITHDRAWALS;
+        assert!(vault
+            .check_exit_window(queued_at, executable_at, executable_at)
+            .is_err());
+
+        // resumed halfway through the window: the full delay runs again from there
+        vault.pause_flags &= !PAUSE_WITHDRAWALS;
+        vault.withdrawals_resumed_at = NOW + 500;
+        assert!(vault
+            .check_exit_window(queued_at, executable_at, executable_at)
+            .is_err());
+        vault
+            .check_exit_window(queued_at, executable_at, NOW + 1_500)
+            .unwrap();
+    }
+
+    #[test]
+    fn a_terminated_vault_redeems_against_realised_cash_only() {
+        // 400 idle cash, 600 still held in custody
+        let mut vault = vault(1_000, 600);
+        assert_eq!(vault.redeemable_assets(), 1_000);
+
+        vault.terminate();
+        assert_eq!(vault.redeemable_assets(), 400);
+
+        // a tenth of the bonds gets a tenth of the cash, leaving the rest of its share behind
+        let payout = accounting::assets_for_shares(
+            100,
+            vault.redeemable_assets(),
+            vault.bond_supply,
+            Rounding::Down,
+        )
+        .unwrap();
+        assert_eq!(payout, 40);
+    }
+
+    #[test]
+    fn accruing_before_the_seed_deposit_charges_nothing_for_the_empty_period() {
+        let mut vault = vault(0, 0);
+        vault.bond_supply = 0;
+        vault.management_fee_bps = 200;
+        vault.last_fee_accrual_ts = NOW - SECONDS_PER_YEAR as i64;
+
+        vault.accrue_fees(NOW).unwrap();
+        vault.tvl = 1_000_000;
+        vault.bond_supply = 1_000_000;
+        vault.accrue_fees(NOW).unwrap();
+        assert_eq!(vault.accrued_fees, 0);
+
+        vault.accrue_fees(NOW + SECONDS_PER_YEAR as i64).unwrap();
+        assert_eq!(vault.accrued_fees, 20_000);
+    }
+
+    #[test]
+    fn quote_into_custody_moves_value_into_holdings() {
+        let mut vault = vault(1_000, 0);
+        vault
+            .book_swap(Pubkey::default(), 400, false, 398, true, NOW)
+            .unwrap();
+
+        assert_eq!(vault.tvl, 998);
+        assert_eq!(vault.holdings_value, 398);
+    }
+
+    #[test]
+    fn custody_into_quote_releases_holdings() {
+        let mut vault = vault(1_000, 600);
+        vault
+            .book_swap(Pubkey::default(), 300, true, 310, false, NOW)
+            .unwrap();
+
+        assert_eq!(vault.tvl, 1_010);
+        assert_eq!(vault.holdings_value, 300);
+    }
+
+    #[test]
+    fn custody_leg_never_releases_more_than_holdings() {
+        let mut vault = vault(1_000, 100);
+        vault
+            .book_swap(Pubkey::default(), 150, true, 140, false, NOW)
+            .unwrap();
+
+        assert_eq!(vault.tvl, 1_040);
+        assert_eq!(vault.holdings_value, 0);
+    }
+
+    #[test]
+    fn closing_a_position_swaps_its_equity_for_the_proceeds() {
+        let mut vault = vault(1_000, 0);
+        vault.positions_value = 300;
+        vault
+            .book_position_close(Pubkey::default(), 120, 150, NOW)
+            .unwrap();
+
+        assert_eq!(vault.positions_value, 180);
+        assert_eq!(vault.tvl, 1_030);
+    }
+
+    #[test]
+    fn reset_only_rebases_the_peak_of_a_tripped_breaker() {
+        let mut vault = vault(900, 0);
+        vault.bond_supply = 1_000;
+        vault.peak_nav = accounting::NAV_PRECISION;
+        vault.reset_drawdown(NOW).unwrap();
+        assert_eq!(vault.peak_nav, accounting::NAV_PRECISION);
+
+        vault.drawdown_tripped_at = NOW;
+        vault.drawdown_cooldown_secs = 60;
+        assert!(vault.reset_drawdown(NOW + 59).is_err());
+        vault.reset_drawdown(NOW + 60).unwrap();
+        assert_eq!(vault.drawdown_tripped_at, 0);
+        assert_eq!(vault.peak_nav, accounting::NAV_PRECISION * 9 / 10);
+    }
+
+    #[test]
+    fn leadership_hand_over_moves_an_undelegated_fee_manager() {
+        let (old_leader, new_leader, delegate) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut vault = vault(0, 0);
+        vault.leader = old_leader;
+        vault.fee_manager = old_leader;
+        vault.hand_over_leadership(new_leader);
+        assert_eq!(vault.fee_manager, new_leader);
+
+        vault.fee_manager = delegate;
+        vault.hand_over_leadership(old_leader);
+        assert_eq!(vault.leader, old_leader);
+        assert_eq!(vault.fee_manager, delegate);
+    }
+
+    #[test]
+    fn replaced_leader_loses_every_role() {
+        let (old_leader, new_leader) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut vault = vault(0, 0);
+        vault.leader = old_leader;
+        vault.fee_manager = Pubkey::new_unique();
+        vault.guardian = old_leader;
+        vault.pending_leader = Pubkey::new_unique();
+        vault.backend_wallet = Pubkey::new_unique();
+        vault.vault_pay_token_account = Pubkey::new_unique();
+        vault.fee_recipient = Pubkey::new_unique();
+        vault.replace_leader(new_leader);
+
+        assert_eq!(vault.leader, new_leader);
+        assert_eq!(vault.fee_manager, new_leader);
+        assert_eq!(vault.guardian, Pubkey::default());
+        assert_eq!(vault.pending_leader, Pubkey::default());
+        assert_eq!(vault.backend_wallet, Pubkey::default());
+        assert!(vault.holds_fees());
+    }
+
+    #[test]
+    fn losing_swap_trips_the_drawdown_breaker() {
+        let mut vault = vault(1_000, 0);
+        vault.max_drawdown_bps = 1_000;
+        vault
+            .book_swap(Pubkey::default(), 500, false, 350, true, NOW)
+            .unwrap();
+
+        assert_eq!(vault.tvl, 850);
+        assert_eq!(vault.drawdown_tripped_at, NOW);
+        assert!(vault.pause_flags & PAUSE_TRADING != 0);
+    }
 }
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct WithdrawRequest {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    // bonds held in the vault's escrow until the request is fulfilled
+    pub bond_amount: u64,
+    // cost basis released from the depositor's position
+    pub deposit_value: u64,
+    pub requested_at: i64,
+    pub bump: u8,
+}
+
+impl WithdrawRequest {
+    pub const LEN: usize = std::mem::size_of::<WithdrawRequest>() + 8;
+}
@@ -0,0 +1,375 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createAccount,
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import { TradingVault } from "../target/types/trading_vault";
+import { MockSwap } from "../target/types/mock_swap";
+
+const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+const BPF_LOADER_UPGRADEABLE_ID = new PublicKey(
+  "BPFLoaderUpgradeab1e11111111111111111111111"
+);
+const PRICE_ACCOUNT_LEN = 240;
+
+const USDC = (amount: number) => new BN(amount * 1_000_000);
+const ASSET = (amount: number) => new BN(amount).mul(new BN(1_000_000_000));
+
+describe("execute_swap", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const program = anchor.workspace.TradingVault as Program<TradingVault>;
+  const mockSwap = anchor.workspace.MockSwap as Program<MockSwap>;
+  const payer = (provider.wallet as anchor.Wallet).payer;
+  const leader = provider.wallet.publicKey;
+  const backendWallet = Keypair.generate();
+
+  const pda = (seeds: Buffer[], programId = program.programId) =>
+    PublicKey.findProgramAddressSync(seeds, programId)[0];
+
+  const protocolConfig = pda([Buffer.from("protocol_config")]);
+  const leaderProfile = pda([Buffer.from("leader_profile"), leader.toBuffer()]);
+  const vault = pda([
+    Buffer.from("vault"),
+    leader.toBuffer(),
+    new BN(0).toArrayLike(Buffer, "le", 8),
+  ]);
+  const vaultAuthority = pda([Buffer.from("vault_authority"), vault.toBuffer()]);
+  const bondMint = pda([Buffer.from("mint"), vault.toBuffer()]);
+  const vaultPay = pda([Buffer.from("vault_pay"), vault.toBuffer()]);
+  const poolAuthority = pda([Buffer.from("pool_authority")], mockSwap.programId);
+  const priceAccount = Keypair.generate();
+
+  let quoteMint: PublicKey;
+  let assetMint: PublicKey;
+  let custody: PublicKey;
+  let poolQuote: PublicKey;
+  let poolAsset: PublicKey;
+
+  const swap = (params: {
+    source: PublicKey;
+    destination: PublicKey;
+    sourceOracle: PublicKey | null;
+    destinationOracle: PublicKey | null;
+    poolSource: PublicKey;
+    poolDestination: PublicKey;
+    amountIn: BN;
+    amountOut: BN;
+    extraAccounts?: PublicKey[];
+  }) =>
+    program.methods
+      .vaultExecuteSwap({
+        amountIn: params.amountIn,
+        minAmountOut: params.amountOut,
+        data: mockSwap.coder.instruction.encode("swap", {
+          amountIn: params.amountIn,
+          amountOut: params.amountOut,
+        }),
+      })
+      .accounts({
+        vault,
+        vaultAuthority,
+        backendWallet: backendWallet.publicKey,
+        protocolConfig,
+        sourceTokenAccount: params.source,
+        destinationTokenAccount: params.destination,
+        swapProgram: mockSwap.programId,
+        sourceOracle: params.sourceOracle,
+        destinationOracle: params.destinationOracle,
+      })
+      .remainingAccounts(
+        [
+          // mock_swap::Swap accounts, the vault authority trading as `trader`
+          { pubkey: vaultAuthority, isSigner: false, isWritable: false },
+          { pubkey: params.source, isSigner: false, isWritable: true },
+          { pubkey: params.destination, isSigner: false, isWritable: true },
+          { pubkey: params.poolSource, isSigner: false, isWritable: true },
+          { pubkey: params.poolDestination, isSigner: false, isWritable: true },
+          { pubkey: poolAuthority, isSigner: false, isWritable: false },
+          { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+        ].concat(
+          (params.extraAccounts ?? []).map((pubkey) => ({
+            pubkey,
+            isSigner: false,
+            isWritable: true,
+          }))
+        )
+      )
+      .signers([backendWallet])
+      .rpc();
+
+  before(async () => {
+    quoteMint = await createMint(provider.connection, payer, leader, null, 6);
+    assetMint = await createMint(provider.connection, payer, leader, null, 9);
+    custody = pda([
+      Buffer.from("custody"),
+      vault.toBuffer(),
+      assetMint.toBuffer(),
+    ]);
+
+    // mock_swap doubles as the oracle program: it owns and writes the price account
+    await program.methods
+      .protocolInitialize({
+        treasury: leader,
+        protocolFeeBps: 0,
+        allowedQuoteMints: [quoteMint],
+        allowedSwapPrograms: [mockSwap.programId],
+        oracleProgram: mockSwap.programId,
+        isPaused: false,
+      })
+      .accounts({
+        admin: leader,
+        protocolConfig,
+        program: program.programId,
+        programData: pda(
+          [program.programId.toBuffer()],
+          BPF_LOADER_UPGRADEABLE_ID
+        ),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await mockSwap.methods
+      // 1 asset = 2 USDC
+      .setPrice(new BN(2_000_000), new BN(0), -6)
+      .accounts({ payer: leader, priceAccount: priceAccount.publicKey })
+      .preInstructions([
+        SystemProgram.createAccount({
+          fromPubkey: leader,
+          newAccountPubkey: priceAccount.publicKey,
+          lamports:
+            await provider.connection.getMinimumBalanceForRentExemption(
+              PRICE_ACCOUNT_LEN
+            ),
+          space: PRICE_ACCOUNT_LEN,
+          programId: mockSwap.programId,
+        }),
+      ])
+      .signers([priceAccount])
+      .rpc();
+
+    const feeRecipient = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      quoteMint,
+      leader
+    );
+    await program.methods
+      .vaultInitialize({
+        strategyId: "swap-test",
+        lockPeriodSecs: new BN(0),
+        maxTvl: new BN(0),
+        maxDepositPerUser: new BN(0),
+        epochLengthSecs: new BN(0),
+        performanceFeeBps: 0,
+        managementFeeBps: 0,
+        entryFeeBps: 0,
+        exitFeeBps: 0,
+        maxNavChangeBps: 10_000,
+        minNavReportIntervalSecs: new BN(0),
+        maxDrawdownBps: 0,
+        paramChangeDelaySecs: new BN(0),
+        governanceQuorumBps: 1_000,
+        governanceThresholdBps: 6_000,
+        governanceVotingPeriodSecs: new BN(86_400),
+        drawdownCooldownSecs: new BN(0),
+        oracleMaxStalenessSecs: new BN(600),
+        oracleMaxConfBps: 100,
+        bondName: "Test Vault Bond",
+        bondSymbol: "TVB",
+        bondUri: "",
+      })
+      .accounts({
+        leader,
+        backendWallet: backendWallet.publicKey,
+        protocolConfig,
+        leaderProfile,
+        vault,
+        vaultAuthority,
+        mintAccount: bondMint,
+        quoteMint,
+        vaultPayTokenAccount: vaultPay,
+        feeRecipient: feeRecipient.address,
+        metadataAccount: pda(
+          [
+            Buffer.from("metadata"),
+            TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            bondMint.toBuffer(),
+          ],
+          TOKEN_METADATA_PROGRAM_ID
+        ),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([backendWallet])
+      .rpc();
+
+    const leaderPay = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      quoteMint,
+      leader
+    );
+    const leaderBonds = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer,
+      bondMint,
+      leader
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      quoteMint,
+      leaderPay.address,
+      payer,
+      1_000_000_000
+    );
+    await program.methods
+      .vaultInitDeposit({ initialDeposit: USDC(100) })
+      .accounts({
+        leader,
+        user: pda([Buffer.from("user"), vault.toBuffer(), leader.toBuffer()]),
+        backendWallet: backendWallet.publicKey,
+        vault,
+        vaultAuthority,
+        mintAccount: bondMint,
+        vaultPayTokenAccount: vaultPay,
+        leaderPayTokenAccount: leaderPay.address,
+        leaderTokenAccount: leaderBonds.address,
+        protocolConfig,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .signers([backendWallet])
+      .rpc();
+
+    await program.methods
+      .vaultRegisterAsset()
+      .accounts({
+        vault,
+        vaultAuthority,
+        leader,
+        assetMint,
+        protocolConfig,
+        oracle: priceAccount.publicKey,
+        custody,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    poolQuote = await createAccount(
+      provider.connection,
+      payer,
+      quoteMint,
+      poolAuthority,
+      Keypair.generate()
+    );
+    poolAsset = await createAccount(
+      provider.connection,
+      payer,
+      assetMint,
+      poolAuthority,
+      Keypair.generate()
+    );
+    await mintTo(
+      provider.connection,
+      payer,
+      assetMint,
+      poolAsset,
+      payer,
+      BigInt(ASSET(100).toString())
+    );
+  });
+
+  it("books a quote-to-asset swap into holdings without changing tvl", async () => {
+    await swap({
+      source: vaultPay,
+      destination: custody,
+      sourceOracle: null,
+      destinationOracle: priceAccount.publicKey,
+      poolSource: poolQuote,
+      poolDestination: poolAsset,
+      amountIn: USDC(20),
+      amountOut: ASSET(10),
+    });
+
+    const state = await program.account.vault.fetch(vault);
+    expect(state.tvl.toString()).to.equal(USDC(100).toString());
+    expect(state.holdingsValue.toString()).to.equal(USDC(20).toString());
+    expect((await getAccount(provider.connection, vaultPay)).amount).to.equal(
+      BigInt(USDC(80).toString())
+    );
+    expect((await getAccount(provider.connection, custody)).amount).to.equal(
+      BigInt(ASSET(10).toString())
+    );
+  });
+
+  it("rejects swaps that pass other vault-held token accounts", async () => {
+    const strayVaultAccount = await createAccount(
+      provider.connection,
+      payer,
+      quoteMint,
+      vaultAuthority,
+      Keypair.generate()
+    );
+
+    try {
+      await swap({
+        source: vaultPay,
+        destination: custody,
+        sourceOracle: null,
+        destinationOracle: priceAccount.publicKey,
+        poolSource: poolQuote,
+        poolDestination: poolAsset,
+        amountIn: USDC(2),
+        amountOut: ASSET(1),
+        extraAccounts: [strayVaultAccount],
+      });
+      expect.fail("swap touching a vault-held account succeeded");
+    } catch (err) {
+      expect((err as anchor.AnchorError).error.errorCode.code).to.equal(
+        "InvalidSwapAccount"
+      );
+    }
+  });
+
+  it("books the loss of an asset-to-quote swap against tvl", async () => {
+    await swap({
+      source: custody,
+      destination: vaultPay,
+      sourceOracle: priceAccount.publicKey,
+      destinationOracle: null,
+      poolSource: poolAsset,
+      poolDestination: poolQuote,
+      amountIn: ASSET(10),
+      amountOut: USDC(18),
+    });
+
+    const state = await program.account.vault.fetch(vault);
+    expect(state.tvl.toString()).to.equal(USDC(98).toString());
+    expect(state.holdingsValue.toString()).to.equal("0");
+  });
+});